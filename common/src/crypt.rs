@@ -0,0 +1,55 @@
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::aead::AeadCore;
+use chacha20poly1305::aead::KeyInit;
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::ChaCha20Poly1305;
+use chacha20poly1305::Nonce;
+use sha2::Digest;
+use sha2::Sha256;
+
+// environment variable with the passphrase for the encrypted puzzle inputs
+pub const KEY_VARIABLE : &str = "AOC_INPUT_KEY";
+
+const NONCE_LENGTH : usize = 12;
+
+pub fn key_from_env() -> String {
+    std::env::var(KEY_VARIABLE).unwrap_or_else(|_| panic!("Set {} to read or write encrypted puzzle inputs", KEY_VARIABLE))
+}
+
+// the 256 bit key is the SHA-256 hash of the passphrase
+fn cipher(passphrase:&str) -> ChaCha20Poly1305 {
+    let key = Sha256::digest(passphrase.as_bytes());
+    ChaCha20Poly1305::new(&key)
+}
+
+// encrypted data = random nonce followed by the ciphertext incl. authentication tag
+pub fn encrypt(passphrase:&str, plaintext:&[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher(passphrase).encrypt(&nonce, plaintext).expect("Could not encrypt");
+    let mut encrypted = nonce.to_vec();
+    encrypted.extend(ciphertext);
+    encrypted
+}
+
+// None if the passphrase is wrong or the data is corrupted
+pub fn decrypt(passphrase:&str, encrypted:&[u8]) -> Option<Vec<u8>> {
+    if encrypted.len() < NONCE_LENGTH { return None; }
+    let (nonce, ciphertext) = encrypted.split_at(NONCE_LENGTH);
+    cipher(passphrase).decrypt(Nonce::from_slice(nonce), ciphertext).ok()
+}
+
+#[test]
+fn test_crypt() {
+    let plaintext = b"Time:      7  15   30\nDistance:  9  40  200\n";
+
+    let encrypted = encrypt("secret", plaintext);
+    assert_ne!(&encrypted[NONCE_LENGTH..NONCE_LENGTH + plaintext.len()], plaintext);
+    assert_eq!(decrypt("secret", &encrypted), Some(plaintext.to_vec()));
+
+    // every encryption uses a fresh nonce
+    assert_ne!(encrypt("secret", plaintext), encrypted);
+
+    assert_eq!(decrypt("wrong", &encrypted), None);
+    assert_eq!(decrypt("secret", &encrypted[..encrypted.len() - 1]), None);
+    assert_eq!(decrypt("secret", &encrypted[..5]), None);
+}
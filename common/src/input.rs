@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::crypt;
+
+// the puzzle input of one day is stored as <data_dir>/day<N>.input
+pub fn input_path(data_dir:&str, day:u32) -> PathBuf {
+    PathBuf::from(data_dir).join(format!("day{}.input", day))
+}
+
+// ... or encrypted as <data_dir>/day<N>.input.enc
+pub fn encrypted_input_path(data_dir:&str, day:u32) -> PathBuf {
+    PathBuf::from(data_dir).join(format!("day{}.input.enc", day))
+}
+
+// read the plain puzzle input of one day if it exists, otherwise decrypt the encrypted one
+pub fn read_input(data_dir:&str, day:u32) -> String {
+    let path = input_path(data_dir, day);
+    if path.exists() {
+        return fs::read_to_string(&path).unwrap_or_else(|_| panic!("Could not read {}", path.display()));
+    }
+
+    let encrypted_path = encrypted_input_path(data_dir, day);
+    if !encrypted_path.exists() {
+        panic!("Could not open {} or {}", path.display(), encrypted_path.display());
+    }
+    let encrypted = fs::read(&encrypted_path).unwrap_or_else(|_| panic!("Could not read {}", encrypted_path.display()));
+    let plaintext = crypt::decrypt(&crypt::key_from_env(), &encrypted)
+        .unwrap_or_else(|| panic!("Could not decrypt {}. Is {} correct?", encrypted_path.display(), crypt::KEY_VARIABLE));
+    String::from_utf8(plaintext).expect("Decrypted input is no UTF-8 text")
+}
+
+// read the puzzle input of one day line by line
+pub fn read_lines(data_dir:&str, day:u32) -> Vec<String> {
+    read_input(data_dir, day).lines().map( |line| line.to_string() ).collect()
+}
+
+// write <data_dir>/day<N>.input.enc from <data_dir>/day<N>.input
+// returns false if there is no plain input for this day
+pub fn encrypt_input(data_dir:&str, day:u32, passphrase:&str) -> bool {
+    let path = input_path(data_dir, day);
+    let Ok(plaintext) = fs::read(&path) else { return false; };
+    let encrypted_path = encrypted_input_path(data_dir, day);
+    fs::write(&encrypted_path, crypt::encrypt(passphrase, &plaintext))
+        .unwrap_or_else(|_| panic!("Could not write {}", encrypted_path.display()));
+    true
+}
+
+// write <data_dir>/day<N>.input from <data_dir>/day<N>.input.enc
+// returns false if there is no encrypted input for this day
+pub fn decrypt_input(data_dir:&str, day:u32, passphrase:&str) -> bool {
+    let encrypted_path = encrypted_input_path(data_dir, day);
+    let Ok(encrypted) = fs::read(&encrypted_path) else { return false; };
+    let plaintext = crypt::decrypt(passphrase, &encrypted)
+        .unwrap_or_else(|| panic!("Could not decrypt {}. Is the passphrase correct?", encrypted_path.display()));
+    let path = input_path(data_dir, day);
+    fs::write(&path, plaintext).unwrap_or_else(|_| panic!("Could not write {}", path.display()));
+    true
+}
+
+#[test]
+fn test_input_path() {
+    assert_eq!(input_path("data", 5), PathBuf::from("data/day5.input"));
+    assert_eq!(input_path("/tmp/year2023/data", 11), PathBuf::from("/tmp/year2023/data/day11.input"));
+    assert_eq!(encrypted_input_path("data", 5), PathBuf::from("data/day5.input.enc"));
+}
+
+#[test]
+fn test_encrypted_input() {
+    let data_dir_path = std::env::temp_dir().join(format!("common_test_encrypted_input_{}", std::process::id()));
+    fs::create_dir_all(&data_dir_path).unwrap();
+    let data_dir = data_dir_path.to_str().unwrap();
+
+    fs::write(input_path(data_dir, 1), "1abc2\r\npqr3stu8vwx\n").unwrap();
+    assert!(encrypt_input(data_dir, 1, "secret"));
+    assert!(!encrypt_input(data_dir, 2, "secret"));
+
+    fs::remove_file(input_path(data_dir, 1)).unwrap();
+    assert!(decrypt_input(data_dir, 1, "secret"));
+    assert_eq!(read_lines(data_dir, 1), vec!["1abc2", "pqr3stu8vwx"]);
+    assert!(!decrypt_input(data_dir, 2, "secret"));
+
+    fs::remove_dir_all(&data_dir_path).unwrap();
+}
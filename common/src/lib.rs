@@ -0,0 +1,7 @@
+// Utilities shared by the solutions of all years:
+// reading the puzzle inputs, the registry of solved days and the answers they return, snapshot tests.
+
+pub mod crypt;
+pub mod input;
+pub mod runner;
+pub mod snapshot;
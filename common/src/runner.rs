@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fmt;
+
+// options for single days, given on the command line as "key=value" or just "flag"
+#[derive(Debug, Default)]
+pub struct Options {
+    values:HashMap<String, String>
+}
+
+impl Options {
+    pub fn parse(options:&[String]) -> Options {
+        let mut values = HashMap::new();
+        for option in options {
+            match option.split_once('=') {
+                Some((key, value)) => values.insert(key.to_string(), value.to_string()),
+                None => values.insert(option.to_string(), String::new())
+            };
+        }
+        Options { values }
+    }
+
+    pub fn get(&self, key:&str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str())
+    }
+
+    pub fn is_set(&self, key:&str) -> bool {
+        self.values.contains_key(key)
+    }
+}
+
+// the answers of both parts of one day, None for a part which is not answered
+// (e.g. when a day only shows a benchmark or an explanation)
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part1:Option<String>,
+    pub part2:Option<String>
+}
+
+impl Answers {
+    pub fn new(part1:impl fmt::Display, part2:impl fmt::Display) -> Answers {
+        Answers { part1: Some(part1.to_string()), part2: Some(part2.to_string()) }
+    }
+}
+
+// the answers or why the day could not be solved
+pub type Solution = Result<Answers, String>;
+
+// "Day 5, part 1: Lowest location is 35" for every answer, "Day 5: <message>" for an error
+pub fn render_solution(day:u32, solution:&Solution) -> String {
+    match solution {
+        Ok(answers) => [&answers.part1, &answers.part2].iter().enumerate()
+            .filter_map(|(index, answer)| answer.as_ref().map(|answer| format!("Day {}, part {}: {}\n", day, index + 1, answer)))
+            .collect(),
+        Err(message) => format!("Day {}: {}\n", day, message)
+    }
+}
+
+// solve both parts of one day, extra information (reports, plots, ...) is printed by the day itself
+pub enum Solver {
+    Plain(fn() -> Solution),
+    WithOptions(fn(&Options) -> Solution)
+}
+
+pub struct Day {
+    pub day:u32,
+    pub solve:Solver
+}
+
+// registry of all solved days of one year
+pub struct Year {
+    pub year:u32,
+    // directory with the puzzle inputs
+    pub data_dir:&'static str,
+    pub days:Vec<Day>
+}
+
+impl Year {
+    pub fn find_day(&self, day:u32) -> Option<&Day> {
+        self.days.iter().find(|candidate| candidate.day == day)
+    }
+
+    // the given days or, if none are given, all days of the year
+    pub fn select_days(&self, days:&[u32]) -> Vec<u32> {
+        if !days.is_empty() { days.to_vec() } else { self.days.iter().map(|day| day.day).collect() }
+    }
+
+    pub fn run(&self, days:&[u32], options:&Options) {
+        for day in self.select_days(days) {
+            let solution = match self.find_day(day).map(|registered| &registered.solve) {
+                Some(Solver::Plain(solve)) => solve(),
+                Some(Solver::WithOptions(solve)) => solve(options),
+                None => { println!("Unknown day {} in year {}", day, self.year); continue; }
+            };
+            print!("{}", render_solution(day, &solution));
+        }
+    }
+}
+
+#[test]
+fn test_options() {
+    let options = Options::parse(&["explain".to_string(), "vocabulary=german".to_string(), "empty=".to_string()]);
+    assert!(options.is_set("explain"));
+    assert_eq!(options.get("explain"), Some(""));
+    assert_eq!(options.get("vocabulary"), Some("german"));
+    assert_eq!(options.get("empty"), Some(""));
+    assert!(!options.is_set("other"));
+    assert_eq!(options.get("other"), None);
+}
+
+#[test]
+fn test_year() {
+    fn solve_nothing() -> Solution { Ok(Answers::default()) }
+    fn solve_nothing_with_options(_options:&Options) -> Solution { Ok(Answers::default()) }
+
+    let year = Year {
+        year: 2000,
+        data_dir: "data",
+        days: vec![Day { day: 1, solve: Solver::Plain(solve_nothing) }, Day { day: 3, solve: Solver::WithOptions(solve_nothing_with_options) }]
+    };
+    assert_eq!(year.find_day(1).map(|day| day.day), Some(1));
+    assert!(year.find_day(2).is_none());
+    assert_eq!(year.find_day(3).map(|day| day.day), Some(3));
+
+    assert_eq!(year.select_days(&[]), vec![1, 3]);
+    assert_eq!(year.select_days(&[3, 2]), vec![3, 2]);
+}
+
+#[test]
+fn test_render_solution() {
+    assert_eq!(render_solution(5, &Ok(Answers::new("Lowest location is 35", 46))),
+        "Day 5, part 1: Lowest location is 35\nDay 5, part 2: 46\n");
+    assert_eq!(render_solution(5, &Ok(Answers { part1: None, part2: Some("46".to_string()) })), "Day 5, part 2: 46\n");
+    assert_eq!(render_solution(5, &Ok(Answers::default())), "");
+    assert_eq!(render_solution(4, &Err("invalid input".to_string())), "Day 4: invalid input\n");
+}
@@ -0,0 +1,56 @@
+use std::fs;
+use std::path::PathBuf;
+
+// environment variable to accept the current renderings as new snapshots
+pub const UPDATE_VARIABLE : &str = "UPDATE_SNAPSHOTS";
+
+pub fn snapshot_path(snapshot_dir:&str, name:&str) -> PathBuf {
+    PathBuf::from(snapshot_dir).join(format!("{}.snap", name))
+}
+
+// lines that differ between expected and actual, "-" for expected and "+" for actual
+pub fn diff_lines(expected:&str, actual:&str) -> String {
+    let expected_lines:Vec<&str> = expected.lines().collect();
+    let actual_lines:Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+    for index in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(index);
+        let actual_line = actual_lines.get(index);
+        if expected_line != actual_line {
+            if let Some(line) = expected_line { diff += &format!("{:4} - {}\n", index + 1, line); }
+            if let Some(line) = actual_line   { diff += &format!("{:4} + {}\n", index + 1, line); }
+        }
+    }
+    if diff.is_empty() && expected != actual {
+        diff += "     (line endings differ)\n";
+    }
+    diff
+}
+
+// compare a rendering with the snapshot <snapshot_dir>/<name>.snap
+// with UPDATE_SNAPSHOTS=1 the snapshot is written instead
+pub fn assert_snapshot(snapshot_dir:&str, name:&str, actual:&str) {
+    let path = snapshot_path(snapshot_dir, name);
+
+    if std::env::var(UPDATE_VARIABLE).is_ok_and(|value| value == "1") {
+        fs::create_dir_all(path.parent().unwrap()).unwrap_or_else(|_| panic!("Could not create directory for {}", path.display()));
+        fs::write(&path, actual).unwrap_or_else(|_| panic!("Could not write {}", path.display()));
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!("Snapshot {} does not exist. Run the test with {}=1 to create it.", path.display(), UPDATE_VARIABLE);
+    };
+    if expected != actual {
+        panic!("Snapshot {} does not match:\n{}Run the test with {}=1 to accept the new rendering.",
+            path.display(), diff_lines(&expected, actual), UPDATE_VARIABLE);
+    }
+}
+
+#[test]
+fn test_diff_lines() {
+    assert_eq!(diff_lines("abc\ndef\n", "abc\ndef\n"), "");
+    assert_eq!(diff_lines("abc\ndef\n", "abc\nxyz\n"), "   2 - def\n   2 + xyz\n");
+    assert_eq!(diff_lines("abc\n", "abc\nxyz\n"), "   2 + xyz\n");
+    assert_eq!(diff_lines("abc\n", "abc"), "     (line endings differ)\n");
+}
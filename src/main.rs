@@ -1,45 +1,89 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-
-const NUMBER_OF_DAYS : u32 = 11;
-
 use clap::Parser;
+use clap::Subcommand;
+use common::runner::Options;
+use common::runner::Year;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The year to handle, the latest year if not given
+    #[arg(long)]
+    year: Option<u32>,
+
     /// The days to handle
-    days: Vec<u32>
+    days: Vec<u32>,
+
+    /// Options for single days, e.g. "vocabulary=german" for day 1 of 2023
+    #[arg(long = "option", short = 'o')]
+    options: Vec<String>
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Encrypt dayN.input to dayN.input.enc with the passphrase in AOC_INPUT_KEY
+    Encrypt {
+        /// The year to handle, the latest year if not given
+        #[arg(long)]
+        year: Option<u32>,
+
+        /// The days to handle
+        days: Vec<u32>
+    },
+    /// Decrypt dayN.input.enc to dayN.input with the passphrase in AOC_INPUT_KEY
+    Decrypt {
+        /// The year to handle, the latest year if not given
+        #[arg(long)]
+        year: Option<u32>,
+
+        /// The days to handle
+        days: Vec<u32>
+    }
+}
+
+fn all_years() -> Vec<Year> {
+    vec![year2023::year()]
+}
+
+// the given year or the latest one
+fn find_year(year:Option<u32>) -> Option<Year> {
+    let years = all_years();
+    let Some(year) = year.or_else(|| years.iter().map(|candidate| candidate.year).max()) else {
+        println!("No years solved");
+        return None;
+    };
+    let found = years.into_iter().find(|candidate| candidate.year == year);
+    if found.is_none() {
+        println!("Unknown year {}", year);
+    }
+    found
+}
+
+// convert the puzzle inputs of the given days with encrypt_input or decrypt_input
+fn convert_inputs(year:Option<u32>, days:&[u32], convert:fn(&str, u32, &str) -> bool, verb:&str) {
+    let Some(year) = find_year(year) else { return; };
+    let passphrase = common::crypt::key_from_env();
+    for day in year.select_days(days) {
+        if convert(year.data_dir, day, &passphrase) {
+            println!("{} input of day {} in year {}", verb, day, year.year);
+        } else {
+            println!("No input to convert for day {} in year {}", day, year.year);
+        }
+    }
 }
 
 fn main() {
     let args = Cli::parse();
 
-    let days = if !args.days.is_empty() { args.days } else { (1..NUMBER_OF_DAYS+1).collect() };
-
-    for day in days {
-        match day {
-            1=>day1::part1and2(),
-            2=>day2::part1and2(),
-            3=>day3::part1and2(),
-            4=>day4::part1and2(),
-            5=>day5::part1and2(),
-            6=>day6::part1and2(),
-            7=>day7::part1and2(),
-            8=>day8::part1and2(),
-            9=>day9::part1and2(),
-            10=>day10::part1and2(),
-            11=>day11::part1and2(),
-            otherday=>println!("Unknown day {}", otherday)
+    match args.command {
+        Some(Command::Encrypt { year, days }) => convert_inputs(year, &days, common::input::encrypt_input, "Encrypted"),
+        Some(Command::Decrypt { year, days }) => convert_inputs(year, &days, common::input::decrypt_input, "Decrypted"),
+        None => {
+            if let Some(year) = find_year(args.year) {
+                year.run(&args.days, &Options::parse(&args.options));
+            }
         }
     }
 }
@@ -0,0 +1,519 @@
+// https://adventofcode.com/2023/day/1
+
+// None if the line contains no digit
+pub fn get_calibration_value1(input: &str) -> Option<u32> {
+
+    const DECIMAL : u32 = 10;
+
+    let mut first_digit:Option<u32> = None;
+    let mut last_digit:Option<u32> = None;
+
+    for c in input.chars() {
+        if c.is_digit(DECIMAL) {
+          let digit = c.to_digit(DECIMAL).expect("Could not convert digit");
+          if first_digit.is_none() {
+            first_digit = Some(digit);
+          }
+          last_digit = Some(digit);
+        }
+    }
+
+    Some(first_digit? * DECIMAL + last_digit?)
+}
+
+#[test]
+fn examples1() {
+    assert_eq!(get_calibration_value1("1abc2"), Some(12));
+    assert_eq!(get_calibration_value1("pqr3stu8vwx"), Some(38));
+    assert_eq!(get_calibration_value1("a1b2c3d4e5f"), Some(15));
+    assert_eq!(get_calibration_value1("treb7uchet"), Some(77));
+    assert_eq!(get_calibration_value1("trebuchet"), None);
+}
+
+use aho_corasick::AhoCorasick;
+
+// the words which are spelled-out digits, e.g. "one" = 1
+// the numerals "0".."9" are always part of the vocabulary
+pub struct Vocabulary {
+    words:Vec<(String, u32)>,
+    // finds all numerals and words in a single scan, also overlapping ones like "eightwo"
+    automaton:AhoCorasick
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DigitKind {
+    Numeral,
+    Word
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DigitMatch {
+    // byte span in the line
+    pub start:usize,
+    pub end:usize,
+    pub digit:u32,
+    pub kind:DigitKind
+}
+
+use DigitKind::*;
+
+impl Vocabulary {
+    pub fn new(words:Vec<(String, u32)>) -> Result<Vocabulary, String> {
+        // an empty word would be found everywhere
+        if let Some((_word, digit)) = words.iter().find(|(word, _digit)| word.is_empty()) {
+            return Err(format!("The word for {} is empty", digit));
+        }
+        let numerals = (0..10).map(|digit| digit.to_string());
+        let patterns:Vec<String> = numerals.chain(words.iter().map(|(word, _digit)| word.clone())).collect();
+        let automaton = AhoCorasick::new(patterns).map_err(|error| format!("Could not build automaton for vocabulary: {}", error))?;
+        Ok(Vocabulary { words, automaton })
+    }
+
+    // only the numerals, as in part 1
+    pub fn numerals() -> Vocabulary {
+        Vocabulary::new(Vec::new()).unwrap()
+    }
+
+    fn from_words(words:[&str;9]) -> Vocabulary {
+        Vocabulary::new(words.iter().zip(1..).map(|(word, digit)| (word.to_string(), digit)).collect()).unwrap()
+    }
+
+    pub fn english() -> Vocabulary {
+        Vocabulary::from_words(["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"])
+    }
+
+    pub fn german() -> Vocabulary {
+        Vocabulary::from_words(["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"])
+    }
+
+    pub fn french() -> Vocabulary {
+        Vocabulary::from_words(["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"])
+    }
+
+    // one word and its digit per line, e.g. "uno 1"
+    pub fn from_str(input:&str) -> Result<Vocabulary, String> {
+        let mut words = Vec::new();
+        for (line_index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() { continue; }
+            let (word, digit) = line.trim().split_once(' ')
+                .ok_or_else(|| format!("Line {}: expected '<word> <digit>', got '{}'", line_index + 1, line))?;
+            let digit = digit.trim().parse::<u32>().ok().filter(|digit| *digit < 10)
+                .ok_or_else(|| format!("Line {}: '{}' is no digit", line_index + 1, digit.trim()))?;
+            words.push((word.to_string(), digit));
+        }
+        Vocabulary::new(words)
+    }
+
+    pub fn from_file(path:&str) -> Result<Vocabulary, String> {
+        let input = std::fs::read_to_string(path).map_err(|_| format!("Could not read vocabulary {}", path))?;
+        Vocabulary::from_str(&input).map_err(|message| format!("Vocabulary {}: {}", path, message))
+    }
+
+    // a built-in vocabulary (english, german, french) or a file with a word list
+    pub fn by_name(name:&str) -> Result<Vocabulary, String> {
+        match name {
+            "english" => Ok(Vocabulary::english()),
+            "german"  => Ok(Vocabulary::german()),
+            "french"  => Ok(Vocabulary::french()),
+            path      => Vocabulary::from_file(path)
+        }
+    }
+
+    fn digit_of_pattern(&self, pattern_index:usize) -> (u32, DigitKind) {
+        if pattern_index < 10 { (pattern_index as u32, Numeral) } else { (self.words[pattern_index - 10].1, Word) }
+    }
+
+    // all numerals and words in the line, ordered by their start
+    pub fn find_digits(&self, input:&str) -> Vec<DigitMatch> {
+        let mut matches:Vec<DigitMatch> = self.automaton.find_overlapping_iter(input).map(|found| {
+            let (digit, kind) = self.digit_of_pattern(found.pattern().as_usize());
+            DigitMatch { start: found.start(), end: found.end(), digit, kind }
+        }).collect();
+        matches.sort_by_key(|found| (found.start, found.end));
+        matches
+    }
+}
+
+#[test]
+fn test_find_digits() {
+    let english = Vocabulary::english();
+    assert_eq!(english.find_digits("two1nine"),
+        vec![DigitMatch { start: 0, end: 3, digit: 2, kind: Word },
+             DigitMatch { start: 3, end: 4, digit: 1, kind: Numeral },
+             DigitMatch { start: 4, end: 8, digit: 9, kind: Word }]);
+    assert_eq!(english.find_digits("wo1nine")[0], DigitMatch { start: 2, end: 3, digit: 1, kind: Numeral });
+    assert_eq!(english.find_digits("1nine")[0], DigitMatch { start: 0, end: 1, digit: 1, kind: Numeral });
+    assert_eq!(english.find_digits(""), vec![]);
+    assert_eq!(english.find_digits("eightwo"),
+        vec![DigitMatch { start: 0, end: 5, digit: 8, kind: Word },
+             DigitMatch { start: 4, end: 7, digit: 2, kind: Word }]);
+
+    assert_eq!(Vocabulary::numerals().find_digits("two1nine"), vec![DigitMatch { start: 3, end: 4, digit: 1, kind: Numeral }]);
+}
+
+#[test]
+fn test_vocabulary() {
+    let german = Vocabulary::german();
+    let digits:Vec<u32> = german.find_digits("sechsiebenfünf0").iter().map(|found| found.digit).collect();
+    assert_eq!(digits, vec![6, 7, 5, 0]);
+    assert_eq!(german.find_digits("xfünf")[0], DigitMatch { start: 1, end: 6, digit: 5, kind: Word });
+
+    let french = Vocabulary::french();
+    let digits:Vec<u32> = french.find_digits("cinquatre").iter().map(|found| found.digit).collect();
+    assert_eq!(digits, vec![5, 4]);
+
+    let custom = Vocabulary::from_str("uno 1\ndos 2\n\ntres 3\n").unwrap();
+    let digits:Vec<u32> = custom.find_digits("dosunotres").iter().map(|found| found.digit).collect();
+    assert_eq!(digits, vec![2, 1, 3]);
+    assert!(custom.find_digits("one").is_empty());
+}
+
+#[test]
+fn test_vocabulary_no_digit() {
+    assert_eq!(Vocabulary::from_str("uno one").err(), Some("Line 1: 'one' is no digit".to_string()));
+    assert_eq!(Vocabulary::from_str("uno 1\ndos").err(), Some("Line 2: expected '<word> <digit>', got 'dos'".to_string()));
+}
+
+#[test]
+fn test_vocabulary_empty_word() {
+    assert_eq!(Vocabulary::new(vec![("uno".to_string(), 1), (String::new(), 2)]).err(), Some("The word for 2 is empty".to_string()));
+}
+
+// first and last digit of a line
+#[derive(Debug, PartialEq)]
+pub struct Calibration {
+    pub first:DigitMatch,
+    pub last:DigitMatch
+}
+
+impl Calibration {
+    pub fn value(&self) -> u32 {
+        const DECIMAL : u32 = 10;
+        self.first.digit * DECIMAL + self.last.digit
+    }
+}
+
+// None if the line contains no digit
+pub fn explain_calibration(input: &str, vocabulary:&Vocabulary) -> Option<Calibration> {
+    let digits = vocabulary.find_digits(input);
+    Some(Calibration { first: *digits.first()?, last: *digits.last()? })
+}
+
+pub fn get_calibration_value2(input: &str, vocabulary:&Vocabulary) -> Option<u32> {
+    explain_calibration(input, vocabulary).map(|calibration| calibration.value())
+}
+
+#[test]
+fn examples2() {
+    let english = Vocabulary::english();
+    assert_eq!(get_calibration_value2("two1nine", &english), Some(29));
+    assert_eq!(get_calibration_value2("eightwothree", &english), Some(83));
+    assert_eq!(get_calibration_value2("abcone2threexyz", &english), Some(13));
+    assert_eq!(get_calibration_value2("xtwone3four", &english), Some(24));
+    assert_eq!(get_calibration_value2("4nineeightseven2", &english), Some(42));
+    assert_eq!(get_calibration_value2("zoneight234", &english), Some(14));
+    assert_eq!(get_calibration_value2("7pqrstsixteen", &english), Some(76));
+    assert_eq!(get_calibration_value2("eightwo", &english), Some(82));
+    assert_eq!(get_calibration_value2("trebuchet", &english), None);
+}
+
+#[test]
+fn examples2_other_languages() {
+    assert_eq!(get_calibration_value2("xsechsieben", &Vocabulary::german()), Some(67));
+    assert_eq!(get_calibration_value2("zweiundfünfzig", &Vocabulary::german()), Some(25));
+    assert_eq!(get_calibration_value2("cinquatre", &Vocabulary::french()), Some(54));
+    assert_eq!(get_calibration_value2("huit3trois", &Vocabulary::french()), Some(83));
+}
+
+#[test]
+fn test_explain_calibration() {
+    let english = Vocabulary::english();
+    assert_eq!(explain_calibration("xtwone3four", &english),
+        Some(Calibration {
+            first: DigitMatch { start: 1, end: 4, digit: 2, kind: Word },
+            last:  DigitMatch { start: 7, end: 11, digit: 4, kind: Word }
+        }));
+    assert_eq!(explain_calibration("treb7uchet", &english),
+        Some(Calibration {
+            first: DigitMatch { start: 4, end: 5, digit: 7, kind: Numeral },
+            last:  DigitMatch { start: 4, end: 5, digit: 7, kind: Numeral }
+        }));
+    assert_eq!(explain_calibration("trebuchet", &english), None);
+}
+
+// explain mode
+
+const LINE_NUMBER_WIDTH : usize = 7;
+
+// "        ^^^           part 2: first word 2 at 0..3"
+fn render_match(line:&str, found:&DigitMatch, label:&str) -> String {
+    let column = line[..found.start].chars().count();
+    let width = line[found.start..found.end].chars().count();
+    let marker = format!("{}{}", " ".repeat(column), "^".repeat(width));
+    let kind = match found.kind { Numeral => "numeral", Word => "word" };
+    format!("{}{:<marker_width$}  {} {} {} at {}..{}\n",
+        " ".repeat(LINE_NUMBER_WIDTH), marker,
+        label, kind, found.digit, found.start, found.end,
+        marker_width = line.chars().count())
+}
+
+// one input line with the first and last digit of both parts
+fn render_explanation(line_number:usize, line:&str, calibrations:&[Option<Calibration>;2]) -> String {
+    let mut rendered = format!("{:>width$}: {}\n", line_number, line, width = LINE_NUMBER_WIDTH - 2);
+    let mut values = Vec::new();
+    for (part, calibration) in [1, 2].iter().zip(calibrations) {
+        match calibration {
+            Some(calibration) => {
+                rendered += &render_match(line, &calibration.first, &format!("part {}: first", part));
+                rendered += &render_match(line, &calibration.last,  &format!("part {}: last ", part));
+                values.push(format!("part {} = {}", part, calibration.value()));
+            },
+            None => {
+                values.push(format!("part {} = ERROR: no digit", part));
+            }
+        }
+    }
+    rendered += &format!("{}{}\n", " ".repeat(LINE_NUMBER_WIDTH), values.join(", "));
+    rendered
+}
+
+#[test]
+fn test_render_explanation() {
+    let numerals = Vocabulary::numerals();
+    let english = Vocabulary::english();
+    let german = Vocabulary::german();
+    let mut rendered = String::new();
+    for (line_number, line) in ["two1nine", "eightwothree", "7pqrstsixteen", "trebuchet"].iter().enumerate() {
+        rendered += &render_explanation(line_number + 1, line,
+            &[explain_calibration(line, &numerals), explain_calibration(line, &english)]);
+    }
+    rendered += &render_explanation(5, "zweiundfünfzig",
+        &[explain_calibration("zweiundfünfzig", &numerals), explain_calibration("zweiundfünfzig", &german)]);
+    crate::assert_snapshot("day1/explanation", &rendered);
+}
+
+// streaming scanner: works on the whole input as bytes without splitting it into strings
+
+use aho_corasick::AhoCorasickBuilder;
+use aho_corasick::Anchored;
+use aho_corasick::Input;
+use aho_corasick::MatchKind;
+use aho_corasick::StartKind;
+
+pub struct CalibrationScanner {
+    // patterns ordered by length: at the same start the shortest pattern is the first digit
+    // and the longest pattern the last digit, like in the order of Vocabulary::find_digits
+    patterns:Vec<(String, u32)>,
+    // leftmost search from the start of a line for the first digit, the shortest pattern wins
+    forward:AhoCorasick,
+    // anchored search at each position from the end of a line for the last digit, the longest pattern wins
+    backward:AhoCorasick,
+    // per byte: does a pattern start with it, and which numeral is it
+    // positions where no pattern starts are skipped, numerals without longer patterns need no automaton
+    byte_classes:[ByteClass;256]
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ByteClass {
+    NoPattern,
+    // the byte is the digit, no longer pattern starts with it
+    OnlyNumeral(u32),
+    NeedsAutomaton
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct CalibrationSum {
+    pub sum:u64,
+    pub lines:usize,
+    pub lines_without_digit:usize
+}
+
+impl CalibrationScanner {
+    pub fn new(vocabulary:&Vocabulary) -> CalibrationScanner {
+        let numerals = (0..10).map(|digit| (digit.to_string(), digit));
+        let mut patterns:Vec<(String, u32)> = numerals.chain(vocabulary.words.iter().cloned()).collect();
+        patterns.sort_by_key(|(word, _digit)| word.len());
+        let words = patterns.iter().map(|(word, _digit)| word);
+        let forward = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostFirst)
+            .build(words.clone())
+            .expect("Could not build forward automaton");
+        let backward = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .start_kind(StartKind::Anchored)
+            .build(words)
+            .expect("Could not build backward automaton");
+        let mut byte_classes = [ByteClass::NoPattern;256];
+        for (word, digit) in &patterns {
+            let first_byte = word.as_bytes()[0] as usize;
+            byte_classes[first_byte] = match (byte_classes[first_byte], word.len()) {
+                (ByteClass::NoPattern, 1) => ByteClass::OnlyNumeral(*digit),
+                _ => ByteClass::NeedsAutomaton
+            };
+        }
+        CalibrationScanner { patterns, forward, backward, byte_classes }
+    }
+
+    // the longest pattern starting at this position
+    fn digit_at(&self, line:&[u8], start:usize) -> Option<u32> {
+        match self.byte_classes[line[start] as usize] {
+            ByteClass::NoPattern => None,
+            ByteClass::OnlyNumeral(digit) => Some(digit),
+            ByteClass::NeedsAutomaton => {
+                let input = Input::new(line).span(start..line.len()).anchored(Anchored::Yes);
+                self.backward.find(input).map(|found| self.patterns[found.pattern().as_usize()].1)
+            }
+        }
+    }
+
+    pub fn first_digit(&self, line:&[u8]) -> Option<u32> {
+        let found = self.forward.find(line)?;
+        Some(self.patterns[found.pattern().as_usize()].1)
+    }
+
+    pub fn last_digit(&self, line:&[u8]) -> Option<u32> {
+        (0..line.len()).rev().find_map(|start| self.digit_at(line, start))
+    }
+
+    pub fn calibration_value(&self, line:&[u8]) -> Option<u32> {
+        const DECIMAL : u32 = 10;
+        Some(self.first_digit(line)? * DECIMAL + self.last_digit(line)?)
+    }
+
+    // sum of the calibration values of all lines, separated by "\n" or "\r\n"
+    pub fn scan(&self, buffer:&[u8]) -> CalibrationSum {
+        let mut result = CalibrationSum::default();
+        let buffer = buffer.strip_suffix(b"\n").unwrap_or(buffer);
+        if buffer.is_empty() { return result; }
+        for line in buffer.split(|byte| *byte == b'\n') {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            match self.calibration_value(line) {
+                Some(value) => result.sum += value as u64,
+                None => result.lines_without_digit += 1
+            }
+            result.lines += 1;
+        }
+        result
+    }
+}
+
+#[test]
+fn test_calibration_scanner() {
+    let scanner = CalibrationScanner::new(&Vocabulary::english());
+    assert_eq!(scanner.first_digit(b"xtwone3four"), Some(2));
+    assert_eq!(scanner.last_digit(b"xtwone3four"), Some(4));
+    assert_eq!(scanner.last_digit(b"eightwo"), Some(2));
+    assert_eq!(scanner.first_digit(b"trebuchet"), None);
+    assert_eq!(scanner.last_digit(b""), None);
+
+    let input = "two1nine\r\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+    assert_eq!(scanner.scan(input.as_bytes()), CalibrationSum { sum: 281, lines: 7, lines_without_digit: 0 });
+    assert_eq!(scanner.scan(b"1abc2\n\ntrebuchet"), CalibrationSum { sum: 12, lines: 3, lines_without_digit: 2 });
+    assert_eq!(scanner.scan(b""), CalibrationSum::default());
+
+    let numerals = CalibrationScanner::new(&Vocabulary::numerals());
+    assert_eq!(numerals.scan(b"1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet"), CalibrationSum { sum: 142, lines: 4, lines_without_digit: 0 });
+
+    // at the same start the shortest word is the first digit and the longest word the last digit, like in find_digits
+    let nested = Vocabulary::from_str("ein 1\neins 2\nxeinsx 3").unwrap();
+    let nested_scanner = CalibrationScanner::new(&nested);
+    assert_eq!(nested_scanner.calibration_value(b"eins"), Some(12));
+    for line in ["eins", "xeinsx", "aeinsb", "einsxeinsx"] {
+        assert_eq!(nested_scanner.calibration_value(line.as_bytes()), get_calibration_value2(line, &nested));
+    }
+}
+
+// pseudo-random calibration document for benchmarks and tests (xorshift, no external crate needed)
+pub fn generate_calibration_document(lines:usize, seed:u64) -> String {
+    const WORDS : [&str;12] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "eightwo", "oneight", "twone"];
+    let mut state = seed.max(1);
+    let mut next = |bound:u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % bound
+    };
+    let mut document = String::new();
+    for _ in 0..lines {
+        let length = 5 + next(50);
+        for _ in 0..length {
+            match next(20) {
+                0     => document.push(char::from(b'0' + next(10) as u8)),
+                1     => document += WORDS[next(WORDS.len() as u64) as usize],
+                _     => document.push(char::from(b'a' + next(26) as u8))
+            }
+        }
+        document.push('\n');
+    }
+    document
+}
+
+#[test]
+fn test_scanner_equals_line_functions() {
+    let document = generate_calibration_document(2000, 42);
+    let english = Vocabulary::english();
+    let numerals_scanner = CalibrationScanner::new(&Vocabulary::numerals());
+    let words_scanner = CalibrationScanner::new(&english);
+    for line in document.lines() {
+        assert_eq!(numerals_scanner.calibration_value(line.as_bytes()), get_calibration_value1(line), "{}", line);
+        assert_eq!(words_scanner.calibration_value(line.as_bytes()), get_calibration_value2(line, &english), "{}", line);
+    }
+}
+
+use std::time::Instant;
+
+// compare the scanner with get_calibration_value1/2 on a generated document
+fn benchmark(vocabulary:&Vocabulary) {
+    const LINES : usize = 200_000;
+    let document = generate_calibration_document(LINES, 2023);
+    println!("Day 1 benchmark: {} lines, {} bytes", LINES, document.len());
+
+    for (part, scan_vocabulary) in [(1, &Vocabulary::numerals()), (2, vocabulary)] {
+        let start = Instant::now();
+        let mut line_sum = 0;
+        for line in document.lines() {
+            let value = if part == 1 { get_calibration_value1(line) } else { get_calibration_value2(line, scan_vocabulary) };
+            line_sum += value.unwrap_or(0) as u64;
+        }
+        let line_duration = start.elapsed();
+
+        let start = Instant::now();
+        let scanner_sum = CalibrationScanner::new(scan_vocabulary).scan(document.as_bytes()).sum;
+        let scanner_duration = start.elapsed();
+
+        println!("       part {}: line functions: sum {} in {:?}", part, line_sum, line_duration);
+        println!("       part {}: scanner:        sum {} in {:?}", part, scanner_sum, scanner_duration);
+        assert_eq!(line_sum, scanner_sum, "Scanner and line functions disagree");
+    }
+}
+
+use common::runner::{Answers, Options, Solution};
+
+// option "vocabulary=<english|german|french|file>" selects the words for part 2
+// option "explain" prints the first and last digit of every line
+// option "benchmark" compares the scanner with the line functions on a generated document
+pub fn part1and2(options:&Options) -> Solution {
+    let vocabulary = Vocabulary::by_name(options.get("vocabulary").unwrap_or("english"))?;
+
+    if options.is_set("benchmark") {
+        benchmark(&vocabulary);
+        return Ok(Answers::default());
+    }
+
+    if options.is_set("explain") {
+        let numerals = Vocabulary::numerals();
+        for (line_index, linetext) in crate::read_input_lines(1).iter().enumerate() {
+            let calibrations = [explain_calibration(linetext, &numerals), explain_calibration(linetext, &vocabulary)];
+            print!("{}", render_explanation(line_index + 1, linetext, &calibrations));
+        }
+    }
+
+    let input = crate::read_input(1);
+    let part1 = CalibrationScanner::new(&Vocabulary::numerals()).scan(input.as_bytes());
+    let part2 = CalibrationScanner::new(&vocabulary).scan(input.as_bytes());
+
+    if part1.lines_without_digit + part2.lines_without_digit > 0 {
+        println!("Day 1: ERROR: lines without digit: {} in part1, {} in part2", part1.lines_without_digit, part2.lines_without_digit);
+    }
+    Ok(Answers::new(format!("Sum of calibration values is {}", part1.sum), format!("Sum of calibration values is {}", part2.sum)))
+}
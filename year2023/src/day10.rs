@@ -502,44 +502,14 @@ fn test_enclosing() {
 ...........";
     let grid1 = Grid::from_strings(input1.split("\n").collect());
     let mut enclosing1 = Enclosing::new(&grid1);
-    assert_eq!(enclosing1.to_string(),
-"...........
-...........
-...........
-...........
-...........
-...........
-...........
-...........
-...........
-");
+    crate::assert_snapshot("day10/enclosing1_empty", &enclosing1.to_string());
 
     let loop1 = Loop::find_loop(&grid1);
     enclosing1.mark_loop(&loop1);
-    assert_eq!(enclosing1.to_string(),
-"...........
-.T-------T.
-.|T-----T|.
-.||.....||.
-.||.....||.
-.|^-T.T-^|.
-.|..|.|..|.
-.^--^.^--^.
-...........
-");
+    crate::assert_snapshot("day10/enclosing1_loop", &enclosing1.to_string());
 
     enclosing1.mark_inside();
-    assert_eq!(enclosing1.to_string(),
-"OOOOOOOOOOO
-OT-------TO
-O|T-----T|O
-O||OOOOO||O
-O||OOOOO||O
-O|^-TOT-^|O
-O|II|O|II|O
-O^--^O^--^O
-OOOOOOOOOOO
-");
+    crate::assert_snapshot("day10/enclosing1_inside", &enclosing1.to_string());
     assert_eq!(enclosing1.count_enclosed_tiles(), 4);
 
 
@@ -559,18 +529,7 @@ L--J.L7...LJS7F-7L7.
     let loop2 = Loop::find_loop(&grid2);
     enclosing2.mark_loop(&loop2);
     enclosing2.mark_inside();
-    assert_eq!(enclosing2.to_string(),
-"OT----TTTTTTTT-TOOOO
-O|T--T||||||||T^OOOO
-O||OT^||||||||^TOOOO
-T^^T^T^^^^||^^I^-TOO
-^--^O^TIII^^TTT-T^TO
-OOOOT-^IITTT^|^T^T^T
-OOOO^TITT||^T|I^T^T|
-OOOOO|T^^^|T^|TT|O^^
-OOOOT^^-TO||O||||OOO
-OOOO^---^O^^O^^^^OOO
-");
+    crate::assert_snapshot("day10/enclosing2_inside", &enclosing2.to_string());
     assert_eq!(enclosing2.count_enclosed_tiles(), 8);
 
 
@@ -590,18 +549,7 @@ L7JLJL-JLJLJL--JLJ.L";
     let loop3 = Loop::find_loop(&grid3);
     enclosing3.mark_loop(&loop3);
     enclosing3.mark_inside();
-    assert_eq!(enclosing3.to_string(),
-"OTTTTTTTTTTTTTTT---T
-O|^^||||||||||||T--^
-O^-T^^^^||||||^^^-TO
-T--^T--T||^^^^ITTT^O
-^---^T-^^^IIIIT^^^OO
-OOOT-^T---TIII^TOOOO
-OOT^TT^TT-^TTII^---T
-OO^-^^T||TT|^TT-TTT|
-OOOOOT^|||||T^^T||^^
-OOOOO^-^^^^^^--^^^OO
-");
+    crate::assert_snapshot("day10/enclosing3_inside", &enclosing3.to_string());
     assert_eq!(enclosing3.count_enclosed_tiles(), 10);
 
 }
@@ -611,26 +559,23 @@ OOOOO^-^^^^^^--^^^OO
 /// Productive usage
 //////////////////////////////////////////
 
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-pub fn part1and2() {
+use common::runner::{Answers, Solution};
 
-    let file = File::open("data/day10.input").expect("Could not open data/day10.input");
-    let reader = BufReader::new(file);
+pub fn part1and2() -> Solution {
 
-    let lines:Vec<String> = reader.lines().map( |line| line.unwrap() ).collect();
+    let lines = crate::read_input_lines(10);
     let lines_ref:Vec<&str> = lines.iter().map( |line| line.as_str() ).collect();
     let grid = Grid::from_strings(lines_ref);
     assert_eq!(grid.width, 140);
     assert_eq!(grid.height, 140);
     let the_loop = Loop::find_loop(&grid);
-    println!("Day 10, Part 1: Distance of farthest point {}", the_loop.get_distance_of_farthest_point());
+    let part1 = format!("Distance of farthest point {}", the_loop.get_distance_of_farthest_point());
 
     let mut enclosing = Enclosing::new(&grid);
     enclosing.mark_loop(&the_loop);
     enclosing.mark_inside();
-    println!("Day 10, Part 2: Number of enclosed tiles is {}", enclosing.count_enclosed_tiles());
+    let part2 = format!("Number of enclosed tiles is {}", enclosing.count_enclosed_tiles());
 
+    Ok(Answers::new(part1, part2))
 }
@@ -140,19 +140,15 @@ fn test_space() {
 /// Productive usage
 //////////////////////////////////////////
 
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-pub fn part1and2() {
+use common::runner::{Answers, Solution};
 
-    let file = File::open("data/day11.input").expect("Could not open data/day11.input");
-    let reader = BufReader::new(file);
+pub fn part1and2() -> Solution {
 
-    let lines:Vec<String> = reader.lines().map( |line| line.unwrap() ).collect();
+    let lines = crate::read_input_lines(11);
     let space = Space::from_image(lines.iter().map( |line| line.as_str() ));
 
-    println!("Day 11, Part 1: Sum of distance of all pairs is {}", space.distance_of_all_pairs::<2>());
-    println!("Day 11, Part 2: Sum of distance of all pairs is {}", space.distance_of_all_pairs::<1000000>());
-
+    Ok(Answers::new(
+        format!("Sum of distance of all pairs is {}", space.distance_of_all_pairs::<2>()),
+        format!("Sum of distance of all pairs is {}", space.distance_of_all_pairs::<1000000>())))
 }
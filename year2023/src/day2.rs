@@ -0,0 +1,498 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+// count of cubes per color
+// colors are ordered alphabetically, a count of 0 is the same as a missing color
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+struct Set {
+    cubes: BTreeMap<String, u32>
+}
+
+impl Set {
+    #[cfg(test)]
+    fn from_counts(counts:&[(&str, u32)]) -> Set {
+        let mut set = Set::default();
+        for (color, count) in counts {
+            set.add(color, *count);
+        }
+        set
+    }
+
+    fn add(&mut self, color:&str, count:u32) {
+        assert!(!self.cubes.contains_key(color), "Color {} given twice", color);
+        self.cubes.insert(color.to_string(), count);
+    }
+
+    fn count(&self, color:&str) -> u32 {
+        *self.cubes.get(color).unwrap_or(&0)
+    }
+
+    // all cubes of all colors, the sum may not fit into u32
+    fn total(&self) -> u64 {
+        self.cubes.values().map(|count| *count as u64).sum()
+    }
+}
+
+impl PartialEq for Set {
+    fn eq(&self, other: &Self) -> bool {
+        self.cubes.keys().chain(other.cubes.keys()).all(|color| self.count(color) == other.count(color))
+    }
+}
+
+// "3 blue, 4 red"
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes:Vec<String> = self.cubes.iter().map(|(color, count)| format!("{} {}", count, color)).collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+#[test]
+fn test_set() {
+    let set = Set::from_counts(&[("red", 4), ("green", 0), ("blue", 3)]);
+    assert_eq!(set.count("red"), 4);
+    assert_eq!(set.count("green"), 0);
+    assert_eq!(set.count("yellow"), 0);
+    assert_eq!(set, Set::from_counts(&[("blue", 3), ("red", 4)]));
+    assert_ne!(set, Set::from_counts(&[("blue", 3), ("red", 5)]));
+    assert_eq!(set.to_string(), "3 blue, 0 green, 4 red");
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+struct Game {
+    index: u32,
+    sets:Vec<Set>
+}
+
+// "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue"
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets:Vec<String> = self.sets.iter().map(|set| set.to_string()).collect();
+        write!(f, "Game {}: {}", self.index, sets.join("; "))
+    }
+}
+
+//////// Parser
+
+use pest::Parser;
+use pest_derive::Parser;
+use pest::iterators::Pair;
+use pest::error::Error;
+use pest::error::ErrorVariant;
+
+#[derive(Parser)]
+#[grammar = "../grammar/day2.pest"]
+struct Day2Parser;
+
+// shows line and column of the problem
+type ParseError = Box<Error<Rule>>;
+
+fn error_at(pair:&Pair<'_, Rule>, message:String) -> ParseError {
+    Box::new(Error::new_from_span(ErrorVariant::CustomError { message }, pair.as_span()))
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Day2Parser::parse(Rule::cubes, "46 blue").unwrap().as_str(), "46 blue");
+    assert_eq!(Day2Parser::parse(Rule::set, "46 blue, 88 green").unwrap().as_str(), "46 blue, 88 green");
+    assert_eq!(Day2Parser::parse(Rule::sets, "46 blue, 88 green; 12 red").unwrap().as_str(), "46 blue, 88 green; 12 red");
+    assert!(Day2Parser::parse(Rule::single_game, "Game 3: 46 blue, 88 green; 12 red").is_ok());
+    assert!(Day2Parser::parse(Rule::single_game, "Game 3: 46 blue, x green").is_err());
+    assert!(Day2Parser::parse(Rule::single_game, "Game 3: 46 blue,, 88 green").is_err());
+    assert!(Day2Parser::parse(Rule::file, "Game 1: 1 red\nGame 2: 2 blue\n").is_ok());
+}
+
+fn build_set(set_rule:Pair<'_, Rule>) -> Result<Set, ParseError> {
+    let mut set = Set::default();
+    for cubes in set_rule.into_inner() {
+        let mut inner = cubes.clone().into_inner();
+        let count_rule = inner.next().unwrap();
+        let color = inner.next().unwrap().as_str();
+        let count = count_rule.as_str().parse::<u32>()
+            .map_err(|_| error_at(&count_rule, format!("count {} is too large", count_rule.as_str())))?;
+        if set.cubes.contains_key(color) {
+            return Err(error_at(&cubes, format!("color {} is given twice in one set", color)));
+        }
+        set.add(color, count);
+    }
+    Ok(set)
+}
+
+fn build_sets(sets_rule:Pair<'_, Rule>) -> Result<Vec<Set>, ParseError> {
+    sets_rule.into_inner().map(build_set).collect()
+}
+
+fn build_game(game_rule:Pair<'_, Rule>) -> Result<Game, ParseError> {
+    let mut inner = game_rule.into_inner();
+    let index_rule = inner.next().unwrap();
+    let index = index_rule.as_str().parse::<u32>()
+        .map_err(|_| error_at(&index_rule, format!("game index {} is too large", index_rule.as_str())))?;
+    let sets = build_sets(inner.next().unwrap())?;
+    Ok(Game{index, sets})
+}
+
+fn build_games(file_rule:Pair<'_, Rule>) -> Result<Vec<Game>, ParseError> {
+    file_rule.into_inner().filter(|entry| entry.as_rule() == Rule::game).map(build_game).collect()
+}
+
+fn parse_set(input:&str) -> Result<Set, ParseError> {
+    let set_rule = Day2Parser::parse(Rule::single_set, input)?.next().unwrap().into_inner().next().unwrap();
+    build_set(set_rule)
+}
+
+#[cfg(test)]
+fn parse_game(input:&str) -> Result<Game, ParseError> {
+    let game_rule = Day2Parser::parse(Rule::single_game, input)?.next().unwrap().into_inner().next().unwrap();
+    build_game(game_rule)
+}
+
+fn parse_games(input:&str) -> Result<Vec<Game>, ParseError> {
+    build_games(Day2Parser::parse(Rule::file, input)?.next().unwrap())
+}
+
+#[test]
+fn test_parse_set() {
+    assert_eq!(parse_set("3 blue, 4 red").unwrap(), Set::from_counts(&[("red", 4), ("green", 0), ("blue", 3)]));
+    assert_eq!(parse_set("1 red, 2 green").unwrap(), Set::from_counts(&[("red", 1), ("green", 2), ("blue", 0)]));
+    assert_eq!(parse_set("2 green").unwrap(), Set::from_counts(&[("red", 0), ("green", 2), ("blue", 0)]));
+    assert_eq!(parse_set("5 yellow, 1 red").unwrap(), Set::from_counts(&[("red", 1), ("yellow", 5)]));
+}
+
+#[test]
+fn test_parse_sets() {
+    let sets_rule = Day2Parser::parse(Rule::sets, "3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap().next().unwrap();
+    assert_eq!(build_sets(sets_rule).unwrap(),
+               [Set::from_counts(&[("red", 4), ("green", 0), ("blue", 3)]),Set::from_counts(&[("red", 1), ("green", 2), ("blue", 6)]),Set::from_counts(&[("red", 0), ("green", 2), ("blue", 0)])]);
+}
+
+#[test]
+fn test_parse_errors() {
+    let duplicate = parse_set("3 blue, 4 blue").unwrap_err();
+    assert_eq!(duplicate.line_col, pest::error::LineColLocation::Span((1, 9), (1, 15)));
+    assert!(duplicate.to_string().contains("color blue is given twice in one set"));
+
+    let too_large = parse_game("Game 1: 99999999999 red").unwrap_err();
+    assert_eq!(too_large.line_col, pest::error::LineColLocation::Span((1, 9), (1, 20)));
+    assert!(too_large.to_string().contains("count 99999999999 is too large"));
+
+    let not_numeric = parse_games("Game 1: 3 blue\nGame 2: 3 blue, x red\n").unwrap_err();
+    assert_eq!(not_numeric.line_col, pest::error::LineColLocation::Pos((2, 17)));
+    assert!(not_numeric.to_string().contains("expected count"));
+
+    let duplicate_in_file = parse_games("Game 1: 3 blue\nGame 2: 1 red; 3 blue, 2 red, 1 blue\n").unwrap_err();
+    assert_eq!(duplicate_in_file.line_col, pest::error::LineColLocation::Span((2, 31), (2, 37)));
+}
+
+#[test]
+fn test_display_round_trip() {
+    let text = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+    let game = parse_game(text).unwrap();
+    assert_eq!(game.to_string(), "Game 1: 3 blue, 4 red; 6 blue, 2 green, 1 red; 2 green");
+    assert_eq!(parse_game(&game.to_string()).unwrap(), game);
+
+    let canonical = "Game 17: 0 red; 5 purple, 2 yellow";
+    assert_eq!(parse_game(canonical).unwrap().to_string(), canonical);
+}
+
+// the bag of part 1
+const DEFAULT_BAG : &str = "12 red, 13 green, 14 blue";
+
+// a color which is not in the bag has no cubes in the bag
+fn is_possible(game:&Game, bag:&Set) -> bool {
+    for set in &game.sets {
+        for (color, count) in &set.cubes {
+            if *count > bag.count(color) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+#[test]
+fn examples1() {
+    let bag = parse_set(DEFAULT_BAG).unwrap();
+    let game1 = parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+    assert_eq!(game1, Game{index:1, sets:[Set::from_counts(&[("red", 4), ("green", 0), ("blue", 3)]), Set::from_counts(&[("red", 1), ("green", 2), ("blue", 6)]), Set::from_counts(&[("red", 0), ("green", 2), ("blue", 0)])].to_vec()});
+    assert_eq!(is_possible(&game1, &bag),  true);
+    let game2 = parse_game("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue").unwrap();
+    assert_eq!(game2, Game{index:2, sets:[Set::from_counts(&[("red", 0), ("green", 2), ("blue", 1)]), Set::from_counts(&[("red", 1), ("green", 3), ("blue", 4)]), Set::from_counts(&[("red", 0), ("green", 1), ("blue", 1)])].to_vec()});
+    assert_eq!(is_possible(&game2, &bag),  true);
+    let game3 = parse_game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red").unwrap();
+    assert_eq!(game3, Game{index:3, sets:[Set::from_counts(&[("red", 20), ("green", 8), ("blue", 6)]), Set::from_counts(&[("red", 4), ("green", 13), ("blue", 5)]), Set::from_counts(&[("red", 1), ("green", 5), ("blue", 0)])].to_vec()});
+    assert_eq!(is_possible(&game3, &bag),  false);
+    let game4 = parse_game("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red").unwrap();
+    assert_eq!(game4, Game{index:4, sets:[Set::from_counts(&[("red", 3), ("green", 1), ("blue", 6)]), Set::from_counts(&[("red", 6), ("green", 3), ("blue", 0)]), Set::from_counts(&[("red", 14), ("green", 3), ("blue", 15)])].to_vec()});
+    assert_eq!(is_possible(&game4, &bag),  false);
+    let game5 = parse_game("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap();
+    assert_eq!(game5, Game{index:5, sets:[Set::from_counts(&[("red", 6), ("green", 3), ("blue", 1)]), Set::from_counts(&[("red", 1), ("green", 2), ("blue", 2)])].to_vec()});
+    assert_eq!(is_possible(&game5, &bag),  true);
+}
+
+use std::cmp;
+
+// get the minimum bag that makes this game possible
+// it contains only the colors shown in the game
+fn get_minimum_bag(game:&Game) -> Set {
+    let mut bag=Set::default();
+    for set in &game.sets {
+        for (color, count) in &set.cubes {
+            let bag_count = bag.cubes.entry(color.clone()).or_insert(0);
+            *bag_count = cmp::max(*bag_count, *count);
+        }
+    }
+
+    bag
+}
+
+use num::BigUint;
+
+// product of the cube counts of all colors in the bag, with any number of colors this gets large
+fn get_power_of_bag(set:&Set) -> BigUint {
+    set.cubes.values().map(|count| BigUint::from(*count)).product()
+}
+
+#[test]
+fn examples2() {
+    let game1 = parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+    let bag1 = get_minimum_bag(&game1);
+    assert_eq!(bag1, Set::from_counts(&[("red", 4), ("green", 2), ("blue", 6)]));
+    assert_eq!(get_power_of_bag(&bag1), BigUint::from(48u32));
+
+    let game2 = parse_game("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue").unwrap();
+    let bag2 = get_minimum_bag(&game2);
+    assert_eq!(bag2, Set::from_counts(&[("red", 1), ("green", 3), ("blue", 4)]));
+    assert_eq!(get_power_of_bag(&bag2), BigUint::from(12u32));
+
+    let game3 = parse_game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red").unwrap();
+    let bag3 = get_minimum_bag(&game3);
+    assert_eq!(bag3, Set::from_counts(&[("red", 20), ("green", 13), ("blue", 6)]));
+    assert_eq!(get_power_of_bag(&bag3), BigUint::from(1560u32));
+
+    let game4 = parse_game("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red").unwrap();
+    let bag4 = get_minimum_bag(&game4);
+    assert_eq!(bag4, Set::from_counts(&[("red", 14), ("green", 3), ("blue", 15)]));
+    assert_eq!(get_power_of_bag(&bag4), BigUint::from(630u32));
+
+    let game5 = parse_game("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap();
+    let bag5 = get_minimum_bag(&game5);
+    assert_eq!(bag5, Set::from_counts(&[("red", 6), ("green", 3), ("blue", 2)]));
+    assert_eq!(get_power_of_bag(&bag5), BigUint::from(36u32));
+}
+
+#[test]
+fn examples_other_colors() {
+    let game = parse_game("Game 7: 3 yellow, 4 red; 1 red, 2 purple; 5 yellow").unwrap();
+    assert!(!is_possible(&game, &parse_set(DEFAULT_BAG).unwrap()));
+    assert!( is_possible(&game, &parse_set("5 yellow, 4 red, 2 purple").unwrap()));
+    assert!(!is_possible(&game, &parse_set("5 yellow, 4 red, 1 purple").unwrap()));
+
+    let bag = get_minimum_bag(&game);
+    assert_eq!(bag, Set::from_counts(&[("red", 4), ("yellow", 5), ("purple", 2)]));
+    assert_eq!(get_power_of_bag(&bag), BigUint::from(40u32));
+
+    // five colors with 100 cubes each do not fit into u32
+    let bag = parse_set("100 red, 100 green, 100 blue, 100 yellow, 100 purple").unwrap();
+    assert_eq!(get_power_of_bag(&bag), BigUint::from(10_000_000_000u64));
+}
+
+//////// Inference of the bag content
+
+// get the minimum bag that makes all games possible
+fn get_minimum_bag_of_games(games:&[Game]) -> Set {
+    let mut bag=Set::default();
+    for game in games {
+        for (color, count) in get_minimum_bag(game).cubes {
+            let bag_count = bag.cubes.entry(color).or_insert(0);
+            *bag_count = cmp::max(*bag_count, count);
+        }
+    }
+
+    bag
+}
+
+// all bags with at most budget cubes which make all games possible, one after the other
+// only the colors of the minimum bag are considered, any other color would need additional cubes
+struct CandidateBags<'a> {
+    minimum_bag: &'a Set,
+    spare: u32,
+    // additional cubes per color of the next bag, None when all bags are done
+    extras: Option<Vec<u32>>
+}
+
+fn get_candidate_bags(minimum_bag:&Set, budget:u32) -> CandidateBags<'_> {
+    // no bag has more cubes than the budget, so the counts of the candidates fit into u32
+    let spare = (budget as u64).checked_sub(minimum_bag.total());
+    CandidateBags {
+        minimum_bag,
+        spare: spare.unwrap_or(0) as u32,
+        extras: spare.map(|_| vec![0; minimum_bag.cubes.len()])
+    }
+}
+
+impl Iterator for CandidateBags<'_> {
+    type Item = Set;
+
+    // the extras in lexicographic order: [0,0,0], [0,0,1], ..., [0,0,spare], [0,1,0], ...
+    fn next(&mut self) -> Option<Set> {
+        let extras = self.extras.as_mut()?;
+        let mut bag = Set::default();
+        for ((color, minimum), extra) in self.minimum_bag.cubes.iter().zip(extras.iter()) {
+            bag.cubes.insert(color.clone(), minimum + extra);
+        }
+
+        let total:u32 = extras.iter().sum();
+        match extras.last_mut() {
+            Some(last) if total < self.spare => *last += 1,
+            // move one cube from the last color with extras to the color before, the rest starts again at 0
+            _ => match extras.iter().rposition(|extra| *extra > 0) {
+                Some(position) if position > 0 => {
+                    extras[position] = 0;
+                    extras[position - 1] += 1;
+                }
+                _ => self.extras = None
+            }
+        }
+        Some(bag)
+    }
+}
+
+// the number of candidate bags: spare cubes distributed to k colors (or not at all) = C(spare + k, k)
+fn count_candidate_bags(minimum_bag:&Set, budget:u32) -> BigUint {
+    let Some(spare) = (budget as u64).checked_sub(minimum_bag.total()) else {
+        return BigUint::from(0u32);
+    };
+    let mut count = BigUint::from(1u32);
+    // C(spare + i, i) = C(spare + i - 1, i - 1) * (spare + i) / i
+    for i in 1..=minimum_bag.cubes.len() as u64 {
+        count = count * (spare + i) / i;
+    }
+    count
+}
+
+// the games ordered by the number of cubes of this color they need, strongest constraint first
+// returns pairs of game index and needed cubes, games which do not show the color are left out
+fn rank_games_by_color(games:&[Game], color:&str) -> Vec<(u32, u32)> {
+    let mut ranking:Vec<(u32, u32)> = games.iter()
+        .map(|game| (game.index, get_minimum_bag(game).count(color)))
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    ranking.sort_by_key(|(index, count)| (cmp::Reverse(*count), *index));
+    ranking
+}
+
+#[test]
+fn test_inference() {
+    let games = parse_games("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+").unwrap();
+
+    let minimum_bag = get_minimum_bag_of_games(&games);
+    assert_eq!(minimum_bag, Set::from_counts(&[("red", 20), ("green", 13), ("blue", 15)]));
+    for game in &games {
+        assert!(is_possible(game, &minimum_bag));
+    }
+
+    assert_eq!(get_candidate_bags(&minimum_bag, 47).count(), 0);
+    assert_eq!(count_candidate_bags(&minimum_bag, 47), BigUint::from(0u32));
+    assert_eq!(get_candidate_bags(&minimum_bag, 48).collect::<Vec<_>>(), std::slice::from_ref(&minimum_bag));
+    let candidates:Vec<Set> = get_candidate_bags(&minimum_bag, 49).collect();
+    assert_eq!(candidates, [
+        Set::from_counts(&[("blue", 15), ("green", 13), ("red", 20)]),
+        Set::from_counts(&[("blue", 15), ("green", 13), ("red", 21)]),
+        Set::from_counts(&[("blue", 15), ("green", 14), ("red", 20)]),
+        Set::from_counts(&[("blue", 16), ("green", 13), ("red", 20)])]);
+    // spare cubes s for 3 colors give (s+1)(s+2)(s+3)/6 bags
+    assert_eq!(get_candidate_bags(&minimum_bag, 58).count(), 286);
+    assert_eq!(count_candidate_bags(&minimum_bag, 58), BigUint::from(286u32));
+    // too many to build them all
+    assert_eq!(count_candidate_bags(&minimum_bag, 1048), BigUint::from(167_668_501u32));
+    assert_eq!(get_candidate_bags(&minimum_bag, 1048).nth(1001), Some(Set::from_counts(&[("blue", 15), ("green", 14), ("red", 20)])));
+    assert_eq!(get_candidate_bags(&Set::default(), 5).collect::<Vec<_>>(), [Set::default()]);
+    // the counts of the minimum bag sum up beyond u32
+    let huge_bag = Set::from_counts(&[("red", u32::MAX), ("green", u32::MAX), ("blue", 2)]);
+    assert_eq!(huge_bag.total(), 2 * u32::MAX as u64 + 2);
+    assert_eq!(get_candidate_bags(&huge_bag, u32::MAX).count(), 0);
+    assert_eq!(count_candidate_bags(&huge_bag, u32::MAX), BigUint::from(0u32));
+    let big_bag = Set::from_counts(&[("red", u32::MAX - 1), ("green", 0)]);
+    assert_eq!(get_candidate_bags(&big_bag, u32::MAX).collect::<Vec<_>>(), [
+        Set::from_counts(&[("red", u32::MAX - 1), ("green", 0)]),
+        Set::from_counts(&[("red", u32::MAX), ("green", 0)]),
+        Set::from_counts(&[("red", u32::MAX - 1), ("green", 1)])]);
+    assert_eq!(count_candidate_bags(&big_bag, u32::MAX), BigUint::from(3u32));
+
+    assert_eq!(rank_games_by_color(&games, "red"),   [(3, 20), (4, 14), (5, 6), (1, 4), (2, 1)]);
+    assert_eq!(rank_games_by_color(&games, "green"), [(3, 13), (2, 3), (4, 3), (5, 3), (1, 2)]);
+    assert_eq!(rank_games_by_color(&games, "yellow"), []);
+}
+
+use common::runner::{Answers, Options, Solution};
+
+// option "bag=<cubes>" replaces the bag of part 1, e.g. "bag=12 red, 13 green, 14 blue, 3 yellow"
+// option "infer" shows which bags are consistent with all games, "budget=<cubes>" limits the candidate bags
+pub fn part1and2(options:&Options) -> Solution {
+    let bag = match parse_set(options.get("bag").unwrap_or(DEFAULT_BAG)) {
+        Ok(bag) => bag,
+        Err(error) => return Err(format!("invalid bag\n{}", error))
+    };
+    let budget = match options.get("budget").map(|budget| (budget, budget.parse::<u32>())) {
+        None => None,
+        Some((_, Ok(budget))) => Some(budget),
+        Some((budget, Err(error))) => return Err(format!("invalid budget '{}', it must be a number of cubes: {}", budget, error))
+    };
+    let games = match parse_games(&crate::read_input(2)) {
+        Ok(games) => games,
+        Err(error) => return Err(format!("invalid input\n{}", error))
+    };
+
+    let mut sum_of_indices = 0;
+    let mut cnt_of_possible_games = 0;
+    let mut cnt_of_games = 0;
+    let mut sum_of_power_of_games = BigUint::from(0u32);
+    for game in &games {
+        if is_possible(game, &bag) {
+            sum_of_indices += game.index;
+            cnt_of_possible_games += 1;
+        }
+        let minimum_bag = get_minimum_bag(game);
+        sum_of_power_of_games += get_power_of_bag(&minimum_bag);
+        cnt_of_games += 1;
+    }
+
+    if options.is_set("infer") {
+        print_inference(&games, budget);
+    }
+
+    Ok(Answers::new(
+        format!("{} of {} games possible. Sum of indices = {}", cnt_of_possible_games, cnt_of_games, sum_of_indices),
+        format!("Sum of power of minimal bags = {}", sum_of_power_of_games)))
+}
+
+fn print_inference(games:&[Game], budget:Option<u32>) {
+    let minimum_bag = get_minimum_bag_of_games(games);
+    let minimum_total = minimum_bag.total();
+    println!("Minimum bag for all games: {} ({} cubes)", minimum_bag, minimum_total);
+
+    for color in minimum_bag.cubes.keys() {
+        let ranking:Vec<String> = rank_games_by_color(games, color).iter().take(5)
+            .map(|(index, count)| format!("game {} needs {}", index, count))
+            .collect();
+        println!("  {}: {}", color, ranking.join(", "));
+    }
+
+    let Some(budget) = budget.or(u32::try_from(minimum_total).ok()) else {
+        println!("No candidate bags, the minimum bag has more than {} cubes", u32::MAX);
+        return;
+    };
+    println!("{} candidate bags with at most {} cubes", count_candidate_bags(&minimum_bag, budget), budget);
+    for candidate in get_candidate_bags(&minimum_bag, budget).take(10) {
+        println!("  {}", candidate);
+    }
+}
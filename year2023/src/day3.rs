@@ -0,0 +1,874 @@
+use regex::Regex;
+use std::cmp;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+use std::time::Instant;
+use num::BigUint;
+
+struct EngineSchematic {
+    lines:Vec<String>
+}
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+struct Number {
+    line:usize,
+    // first column of the number
+    from:usize,
+    // last column of the number
+    to:usize,
+    value:u32
+}
+
+fn extract_numbers(engine_schematic:&EngineSchematic) -> Vec<Number> {
+    let mut numbers=Vec::new();
+    let re = Regex::new(r"(\d+)").unwrap();
+    let mut line_number = 0;
+    for line in &engine_schematic.lines {
+        for number_match in re.find_iter(&line) {
+            let value = number_match.as_str().parse::<u32>().unwrap();
+            numbers.push(
+                Number { 
+                    line:  line_number,
+                    from:  number_match.start(),
+                    to:    number_match.end()-1,
+                    value: value
+                }
+            );
+        }
+        line_number += 1;
+    }
+    numbers
+}
+
+#[test]
+fn test_regex() {
+    let re_any_symbol = Regex::new(r"[^0123456789\.]").unwrap();
+    assert!(!re_any_symbol.is_match("......"));    
+    assert!(!re_any_symbol.is_match("..343.."));    
+    assert!(re_any_symbol.is_match("...*.."));
+    assert!(re_any_symbol.is_match("...+.."));    
+}
+
+// compiled only once, find_symbol is called for every number
+fn re_any_symbol() -> &'static Regex {
+    static RE_ANY_SYMBOL: OnceLock<Regex> = OnceLock::new();
+    RE_ANY_SYMBOL.get_or_init(|| Regex::new(r"[^0123456789\.]").unwrap())
+}
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+struct Symbol {
+    line:usize,
+    column:usize,
+    character:char
+}
+
+fn find_symbol(engine_schematic:&EngineSchematic, fromline:isize, toline:usize, fromcol: isize, tocol: usize) -> Option<Symbol> {
+    let fromline_safe = cmp::max(fromline, 0) as usize;
+    let fromcol_safe  = cmp::max(fromcol, 0) as usize;
+    let toline_safe   = cmp::min(toline, engine_schematic.lines.len() - 1);
+    let tocol_safe    = cmp::min(tocol, engine_schematic.lines[0].len() - 1);
+    let re_any_symbol = re_any_symbol();
+    //println!("Line from {} to {}", fromline_safe, toline_safe);
+
+    for lineindex in fromline_safe..toline_safe+1 {
+        let line = &engine_schematic.lines[lineindex];
+        let section = &line[fromcol_safe..tocol_safe+1];
+        //println!("  Search from {} to {} in {}: {}", fromcol_safe, tocol_safe, line, section);
+        if let Some(symbolmatch) = re_any_symbol.find(section) {
+            //println!("  Found!");
+            let column = fromcol_safe+symbolmatch.start();
+            let character = symbolmatch.as_str().chars().next().unwrap();
+            return Some(Symbol{line:lineindex, column, character});
+        }
+    }
+    None
+}
+
+// like find_symbol, but all symbols in the area
+fn find_symbols(engine_schematic:&EngineSchematic, fromline:isize, toline:usize, fromcol: isize, tocol: usize) -> Vec<Symbol> {
+    let fromline_safe = cmp::max(fromline, 0) as usize;
+    let fromcol_safe  = cmp::max(fromcol, 0) as usize;
+    let toline_safe   = cmp::min(toline, engine_schematic.lines.len() - 1);
+    let tocol_safe    = cmp::min(tocol, engine_schematic.lines[0].len() - 1);
+    let re_any_symbol = re_any_symbol();
+
+    let mut symbols = Vec::new();
+    for lineindex in fromline_safe..toline_safe+1 {
+        let line = &engine_schematic.lines[lineindex];
+        let section = &line[fromcol_safe..tocol_safe+1];
+        for symbolmatch in re_any_symbol.find_iter(section) {
+            let column = fromcol_safe+symbolmatch.start();
+            let character = symbolmatch.as_str().chars().next().unwrap();
+            symbols.push(Symbol{line:lineindex, column, character});
+        }
+    }
+    symbols
+}
+
+fn is_part_number(engine_schematic:&EngineSchematic, number:&Number) -> bool {
+    match find_symbol(engine_schematic, number.line as isize - 1, number.line + 1, number.from as isize - 1, number.to + 1) {
+        Some(_symbol) => true,
+        None => false
+    }
+}
+
+#[test]
+fn examples1() {
+    let engi = EngineSchematic{
+        lines: [
+            String::from("467..114.."),
+            String::from("...*......"),
+            String::from("..35..633."),
+            String::from("......#..."),
+            String::from("617*......"),
+            String::from(".....+.58."),
+            String::from("..592....."),
+            String::from("......755."),
+            String::from("...$.*...."),
+            String::from(".664.598..")
+        ].to_vec()
+    };
+    assert_eq!(engi.lines[3], "......#...");
+
+    let numbers = extract_numbers(&engi);
+    assert_eq!(numbers.len(), 10);
+    assert_eq!(numbers[2], Number{line:2, from: 2, to: 3, value: 35});
+    assert_eq!(numbers[3], Number{line:2, from: 6, to: 8, value: 633});
+
+    assert_eq!(find_symbol(&engi, -1, 1, -1, 3), Some(Symbol{line:1, column:3, character:'*'}));
+    assert_eq!(find_symbol(&engi, -1, 0, -1, 3), None);
+    assert_eq!(find_symbol(&engi, -1, 1, -1, 2), None);
+    assert_eq!(find_symbol(&engi, 2, 4, 6, 8), Some(Symbol{line:3, column:6, character:'#'}));
+    assert_eq!(find_symbol(&engi, 2, 4, 7, 8), None);
+    assert_eq!(find_symbol(&engi, 2, 4, 4, 6), Some(Symbol{line:3, column:6, character:'#'}));
+    assert_eq!(find_symbol(&engi, 2, 4, 4, 5), None);
+
+    assert_eq!(find_symbols(&engi, 2, 4, 4, 6), [Symbol{line:3, column:6, character:'#'}]);
+    assert_eq!(find_symbols(&engi, 3, 5, 2, 5), [Symbol{line:4, column:3, character:'*'}, Symbol{line:5, column:5, character:'+'}]);
+    assert_eq!(find_symbols(&engi, 2, 4, 4, 5), []);
+
+    assert_eq!(is_part_number(&engi, &numbers[0]), true );
+    assert_eq!(is_part_number(&engi, &numbers[1]), false );
+    assert_eq!(is_part_number(&engi, &numbers[2]), true );
+    assert_eq!(is_part_number(&engi, &numbers[3]), true );
+    assert_eq!(is_part_number(&engi, &numbers[4]), true );
+    assert_eq!(is_part_number(&engi, &numbers[5]), false );
+    assert_eq!(is_part_number(&engi, &numbers[6]), true );
+    assert_eq!(is_part_number(&engi, &numbers[7]), true );
+    assert_eq!(is_part_number(&engi, &numbers[8]), true );
+    assert_eq!(is_part_number(&engi, &numbers[9]), true );
+
+}
+
+// which numbers touch which symbols, in both directions
+// numbers and symbols are referred to by their index
+struct AdjacencyGraph {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    // store for one number the list of all adjacent symbols
+    symbols_of_number: Vec<Vec<usize>>,
+    // store for one symbol the list of all adjacent numbers
+    numbers_of_symbol: Vec<Vec<usize>>
+}
+
+// searches the symbols around every number, see get_adjacencies for the fast way
+fn search_adjacencies(engi: &EngineSchematic) -> AdjacencyGraph {
+    let numbers = extract_numbers(engi);
+    let mut symbols = Vec::new();
+    let mut symbol_indices:HashMap<(usize, usize), usize> = HashMap::new();
+    let mut symbols_of_number = Vec::new();
+    let mut numbers_of_symbol:Vec<Vec<usize>> = Vec::new();
+
+    for (number_index, number) in numbers.iter().enumerate() {
+        let mut adjacent_symbols = Vec::new();
+        for symbol in find_symbols(engi, number.line as isize - 1, number.line + 1, number.from as isize - 1, number.to + 1) {
+            let symbol_index = *symbol_indices.entry((symbol.line, symbol.column)).or_insert_with(|| {
+                symbols.push(symbol);
+                numbers_of_symbol.push(Vec::new());
+                symbols.len() - 1
+            });
+            numbers_of_symbol[symbol_index].push(number_index);
+            adjacent_symbols.push(symbol_index);
+        }
+        symbols_of_number.push(adjacent_symbols);
+    }
+    AdjacencyGraph{numbers, symbols, symbols_of_number, numbers_of_symbol}
+}
+
+//////// Single pass scan
+
+fn is_symbol(byte:u8) -> bool {
+    byte != b'.' && !byte.is_ascii_digit()
+}
+
+// append numbers and symbols of one line, ordered by column
+// the columns are byte offsets, so only ASCII schematics are accepted
+fn scan_line(line_index:usize, line:&str, numbers:&mut Vec<Number>, symbols:&mut Vec<Symbol>) -> Result<(), String> {
+    if let Some((column, character)) = line.char_indices().find(|(_, character)| !character.is_ascii()) {
+        return Err(format!("line {}, column {}: '{}' is no ASCII character", line_index + 1, line[..column].chars().count() + 1, character));
+    }
+    let bytes = line.as_bytes();
+    let mut column = 0;
+    while column < bytes.len() {
+        if bytes[column].is_ascii_digit() {
+            let from = column;
+            let mut value:Option<u32> = Some(0);
+            while column < bytes.len() && bytes[column].is_ascii_digit() {
+                let digit = (bytes[column] - b'0') as u32;
+                value = value.and_then(|value| value.checked_mul(10)).and_then(|value| value.checked_add(digit));
+                column += 1;
+            }
+            let value = value.ok_or_else(|| format!("line {}, column {}: number {} is too large", line_index + 1, from + 1, &line[from..column]))?;
+            numbers.push(Number{line:line_index, from, to:column - 1, value});
+        } else {
+            if is_symbol(bytes[column]) {
+                symbols.push(Symbol{line:line_index, column, character:bytes[column] as char});
+            }
+            column += 1;
+        }
+    }
+    Ok(())
+}
+
+// scan the schematic line by line and connect the numbers of a line
+// as soon as the symbols of the next line are known
+// the symbols of a neighbor line are visited with a cursor which only moves forward,
+// so every symbol is only looked at for the numbers around it
+fn get_adjacencies(engi: &EngineSchematic) -> Result<AdjacencyGraph, String> {
+    let mut numbers = Vec::new();
+    let mut symbols = Vec::new();
+    // first number and first symbol of every line, the last entry is the end
+    let mut number_starts = vec![0];
+    let mut symbol_starts = vec![0];
+    let mut symbols_of_number = Vec::new();
+    let line_count = engi.lines.len();
+
+    for line_index in 0..=line_count {
+        if line_index < line_count {
+            scan_line(line_index, &engi.lines[line_index], &mut numbers, &mut symbols)?;
+            number_starts.push(numbers.len());
+            symbol_starts.push(symbols.len());
+        }
+        if line_index == 0 {
+            continue;
+        }
+
+        let number_line = line_index - 1;
+        let neighbor_lines = number_line.saturating_sub(1)..cmp::min(number_line + 2, line_count);
+        let mut cursors:Vec<usize> = neighbor_lines.clone().map(|neighbor| symbol_starts[neighbor]).collect();
+        for number in &numbers[number_starts[number_line]..number_starts[number_line + 1]] {
+            let mut adjacent_symbols = Vec::new();
+            for (cursor, neighbor) in cursors.iter_mut().zip(neighbor_lines.clone()) {
+                let end = symbol_starts[neighbor + 1];
+                while *cursor < end && symbols[*cursor].column + 1 < number.from {
+                    *cursor += 1;
+                }
+                let mut symbol_index = *cursor;
+                while symbol_index < end && symbols[symbol_index].column <= number.to + 1 {
+                    adjacent_symbols.push(symbol_index);
+                    symbol_index += 1;
+                }
+            }
+            symbols_of_number.push(adjacent_symbols);
+        }
+    }
+
+    let mut numbers_of_symbol = vec![Vec::new(); symbols.len()];
+    for (number_index, adjacent_symbols) in symbols_of_number.iter().enumerate() {
+        for symbol_index in adjacent_symbols {
+            numbers_of_symbol[*symbol_index].push(number_index);
+        }
+    }
+    Ok(AdjacencyGraph{numbers, symbols, symbols_of_number, numbers_of_symbol})
+}
+
+impl AdjacencyGraph {
+    #[cfg(test)]
+    fn numbers_of(&self, symbol:&Symbol) -> Vec<&Number> {
+        match self.symbols.iter().position(|s| s == symbol) {
+            Some(symbol_index) => self.numbers_of_symbol[symbol_index].iter().map(|n| &self.numbers[*n]).collect(),
+            None => Vec::new()
+        }
+    }
+
+    fn symbols_with_numbers(&self, count:usize) -> Vec<&Symbol> {
+        self.symbols.iter().zip(&self.numbers_of_symbol)
+            .filter(|(_, numbers)| numbers.len() == count)
+            .map(|(symbol, _)| symbol)
+            .collect()
+    }
+
+    fn part_numbers(&self) -> Vec<&Number> {
+        self.numbers.iter().zip(&self.symbols_of_number)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+            .collect()
+    }
+
+    fn numbers_without_symbol(&self) -> Vec<&Number> {
+        self.numbers.iter().zip(&self.symbols_of_number)
+            .filter(|(_, symbols)| symbols.is_empty())
+            .map(|(number, _)| number)
+            .collect()
+    }
+
+    // parts which are connected by shared symbols, each ordered like in the schematic
+    fn connected_components(&self) -> Vec<Vec<&Number>> {
+        let mut visited = vec![false; self.numbers.len()];
+        let mut components = Vec::new();
+        for start in 0..self.numbers.len() {
+            if visited[start] || self.symbols_of_number[start].is_empty() {
+                continue;
+            }
+            visited[start] = true;
+            let mut component = Vec::new();
+            let mut todo = VecDeque::from([start]);
+            while let Some(number_index) = todo.pop_front() {
+                component.push(number_index);
+                for symbol_index in &self.symbols_of_number[number_index] {
+                    for neighbor in &self.numbers_of_symbol[*symbol_index] {
+                        if !visited[*neighbor] {
+                            visited[*neighbor] = true;
+                            todo.push_back(*neighbor);
+                        }
+                    }
+                }
+            }
+            component.sort();
+            components.push(component.iter().map(|n| &self.numbers[*n]).collect());
+        }
+        components
+    }
+}
+
+//////// Gears
+
+// how many numbers a symbol must touch to be a gear
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumberCount {
+    Exactly(usize),
+    AtLeast(usize)
+}
+
+impl NumberCount {
+    fn matches(&self, count:usize) -> bool {
+        match self {
+            NumberCount::Exactly(n) => count == *n,
+            NumberCount::AtLeast(n) => count >= *n
+        }
+    }
+}
+
+// how the part numbers of a gear are combined to its ratio
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Aggregation {
+    Product,
+    Sum,
+    Max
+}
+
+impl Aggregation {
+    fn aggregate(&self, part_numbers:&[u32]) -> BigUint {
+        let values = part_numbers.iter().map(|value| BigUint::from(*value));
+        match self {
+            Aggregation::Product => values.product(),
+            Aggregation::Sum     => values.sum(),
+            Aggregation::Max     => values.max().unwrap_or_default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct GearRule {
+    // None applies to every symbol
+    symbol:Option<char>,
+    count:NumberCount,
+    aggregation:Aggregation
+}
+
+// the first rule for the character of a symbol decides whether the symbol is a gear
+#[derive(Debug, Clone, PartialEq)]
+struct GearRules {
+    rules:Vec<GearRule>
+}
+
+// the rule of part 2
+impl Default for GearRules {
+    fn default() -> Self {
+        GearRules{ rules: vec![GearRule{ symbol:None, count:NumberCount::Exactly(2), aggregation:Aggregation::Product }] }
+    }
+}
+
+impl GearRules {
+    // comma separated rules of "<symbol or any> <count, or count+ for at least> <product|sum|max>",
+    // e.g. "* 2 product, # 3+ sum"
+    fn parse(text:&str) -> Result<GearRules, String> {
+        let mut rules = Vec::new();
+        for rule_text in text.split(',') {
+            let parts:Vec<&str> = rule_text.split_whitespace().collect();
+            if parts.len() != 3 {
+                return Err(format!("Gear rule '{}' needs a symbol, a count and an aggregation", rule_text.trim()));
+            }
+            let symbol = match parts[0] {
+                "any" => None,
+                symbol if symbol.chars().count() == 1 => symbol.chars().next(),
+                symbol => return Err(format!("Gear rule symbol '{}' is neither a single character nor 'any'", symbol))
+            };
+            let count = match parts[1].strip_suffix('+') {
+                Some(at_least) => at_least.parse().map(NumberCount::AtLeast),
+                None           => parts[1].parse().map(NumberCount::Exactly)
+            }.map_err(|_| format!("Gear rule count '{}' is not a number", parts[1]))?;
+            let aggregation = match parts[2] {
+                "product" => Aggregation::Product,
+                "sum"     => Aggregation::Sum,
+                "max"     => Aggregation::Max,
+                other     => return Err(format!("Gear rule aggregation '{}' is not product, sum or max", other))
+            };
+            rules.push(GearRule{symbol, count, aggregation});
+        }
+        Ok(GearRules{rules})
+    }
+
+    fn rule_for(&self, character:char) -> Option<&GearRule> {
+        self.rules.iter().find(|rule| rule.symbol.is_none_or(|symbol| symbol == character))
+    }
+}
+
+#[test]
+fn test_gear_rules() {
+    let rules = GearRules::parse("* 2 product, # 3+ sum,any 1 max").unwrap();
+    assert_eq!(rules.rules, [
+        GearRule{ symbol:Some('*'), count:NumberCount::Exactly(2), aggregation:Aggregation::Product },
+        GearRule{ symbol:Some('#'), count:NumberCount::AtLeast(3), aggregation:Aggregation::Sum },
+        GearRule{ symbol:None,      count:NumberCount::Exactly(1), aggregation:Aggregation::Max }]);
+    assert_eq!(rules.rule_for('#').unwrap().count, NumberCount::AtLeast(3));
+    assert_eq!(rules.rule_for('+').unwrap().aggregation, Aggregation::Max);
+    assert_eq!(GearRules::parse("* 2 product").unwrap().rule_for('+'), None);
+
+    assert!(GearRules::parse("* 2").unwrap_err().contains("needs a symbol"));
+    assert!(GearRules::parse("** 2 sum").unwrap_err().contains("'**'"));
+    assert!(GearRules::parse("* two sum").unwrap_err().contains("'two'"));
+    assert!(GearRules::parse("* 2+ min").unwrap_err().contains("'min'"));
+
+    assert!(NumberCount::AtLeast(3).matches(4));
+    assert!(!NumberCount::Exactly(3).matches(4));
+    assert_eq!(Aggregation::Product.aggregate(&[4_000_000, 5_000_000, 6_000_000]), BigUint::from(120_000_000_000_000_000_000u128));
+    assert_eq!(Aggregation::Sum.aggregate(&[u32::MAX, 1]), BigUint::from(u32::MAX as u64 + 1));
+    assert_eq!(Aggregation::Max.aggregate(&[3, 9, 4]), BigUint::from(9u32));
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+struct Gear {
+    symbol:char,
+    part_numbers:Vec<u32>,
+    ratio:BigUint
+}
+
+#[cfg(test)]
+fn get_gears(engi: &EngineSchematic) -> Vec<Gear> {
+    get_gears_of(&get_adjacencies(engi).unwrap(), &GearRules::default())
+}
+
+// the rule which makes this symbol a gear
+fn gear_rule<'a>(adj: &AdjacencyGraph, symbol_index:usize, rules: &'a GearRules) -> Option<&'a GearRule> {
+    rules.rule_for(adj.symbols[symbol_index].character)
+        .filter(|rule| rule.count.matches(adj.numbers_of_symbol[symbol_index].len()))
+}
+
+fn get_gears_of(adj: &AdjacencyGraph, rules: &GearRules) -> Vec<Gear> {
+    let mut gears = Vec::new();
+    for (symbol_index, symbol) in adj.symbols.iter().enumerate() {
+        if let Some(rule) = gear_rule(adj, symbol_index, rules) {
+            let part_numbers:Vec<u32> = adj.numbers_of_symbol[symbol_index].iter().map(|n| adj.numbers[*n].value).collect();
+            let ratio = rule.aggregation.aggregate(&part_numbers);
+            gears.push(Gear{ symbol:symbol.character, part_numbers, ratio });
+        }
+    }
+    gears
+}
+
+#[test]
+fn examples2() {
+    let engi = EngineSchematic{
+        lines: [
+            String::from("467..114.."),
+            String::from("...*......"),
+            String::from("..35..633."),
+            String::from("......#..."),
+            String::from("617*......"),
+            String::from(".....+.58."),
+            String::from("..592....."),
+            String::from("......755."),
+            String::from("...$.*...."),
+            String::from(".664.598..")
+        ].to_vec()
+    };
+    let adj = get_adjacencies(&engi).unwrap();
+    assert_eq!(adj.symbols.len(), 6);
+    let sym1nums = adj.numbers_of(&Symbol{line:1, column: 3, character:'*'});
+    assert_eq!(sym1nums.len(), 2);
+    assert_eq!(sym1nums.get(0).unwrap().value, 467);
+    assert_eq!(sym1nums.get(1).unwrap().value, 35);
+
+    let sym2nums = adj.numbers_of(&Symbol{line:8, column: 5, character:'*'});
+    assert_eq!(sym2nums.len(), 2);
+    assert_eq!(sym2nums.get(0).unwrap().value, 755);
+    assert_eq!(sym2nums.get(1).unwrap().value, 598);
+
+    let sym3nums = adj.numbers_of(&Symbol{line:4, column: 3, character:'*'});
+    assert_eq!(sym3nums.len(), 1);
+    assert_eq!(sym3nums.get(0).unwrap().value, 617);
+
+    let gears = get_gears(&engi);
+    assert_eq!(gears.len(), 2);
+    assert!(gears.contains(&Gear{symbol:'*', part_numbers:vec![467,  35], ratio: BigUint::from( 16345u32)}));
+    assert!(gears.contains(&Gear{symbol:'*', part_numbers:vec![755, 598], ratio: BigUint::from(451490u32)}));
+}
+
+#[test]
+fn test_scan_rejects() {
+    let engi = EngineSchematic{ lines: [String::from("1..."), String::from("..€2")].to_vec() };
+    assert_eq!(get_adjacencies(&engi).err(), Some(String::from("line 2, column 3: '€' is no ASCII character")));
+    let engi = EngineSchematic{ lines: [String::from("4294967295*"), String::from(".4294967296")].to_vec() };
+    assert_eq!(get_adjacencies(&engi).err(), Some(String::from("line 2, column 2: number 4294967296 is too large")));
+}
+
+#[test]
+fn test_adjacency_graph() {
+    // 12 touches both symbols, so both are gears; 7 touches no symbol
+    let engi = EngineSchematic{
+        lines: [
+            String::from("5.....7"),
+            String::from("*12#..."),
+            String::from("...3..."),
+            String::from("...+..."),
+            String::from("..8..9=")
+        ].to_vec()
+    };
+    let adj = get_adjacencies(&engi).unwrap();
+    assert_eq!(adj.symbols.len(), 4);
+    assert_eq!(adj.numbers_of(&Symbol{line:1, column:0, character:'*'}).iter().map(|n| n.value).collect::<Vec<u32>>(), [5, 12]);
+    assert_eq!(adj.numbers_of(&Symbol{line:1, column:3, character:'#'}).iter().map(|n| n.value).collect::<Vec<u32>>(), [12, 3]);
+    assert_eq!(adj.numbers_of(&Symbol{line:3, column:3, character:'+'}).iter().map(|n| n.value).collect::<Vec<u32>>(), [3, 8]);
+
+    let with_two:Vec<char> = adj.symbols_with_numbers(2).iter().map(|s| s.character).collect();
+    assert_eq!(with_two, ['*', '#', '+']);
+    assert_eq!(adj.symbols_with_numbers(1), [&Symbol{line:4, column:6, character:'='}]);
+    assert_eq!(adj.numbers_without_symbol(), [&Number{line:0, from:6, to:6, value:7}]);
+
+    let components:Vec<Vec<u32>> = adj.connected_components().iter()
+        .map(|component| component.iter().map(|n| n.value).collect())
+        .collect();
+    assert_eq!(components, [[5, 12, 3, 8].to_vec(), [9].to_vec()]);
+
+    let gears = get_gears(&engi);
+    assert_eq!(gears.len(), 3);
+    assert!(gears.contains(&Gear{symbol:'*', part_numbers:vec![5, 12], ratio: BigUint::from(60u32)}));
+    assert!(gears.contains(&Gear{symbol:'#', part_numbers:vec![12, 3], ratio: BigUint::from(36u32)}));
+
+    let rules = GearRules::parse("* 2 product, # 2+ sum, = 1 max").unwrap();
+    let gears = get_gears_of(&adj, &rules);
+    assert_eq!(gears.len(), 3);
+    assert!(gears.contains(&Gear{symbol:'*', part_numbers:vec![5, 12], ratio: BigUint::from(60u32)}));
+    assert!(gears.contains(&Gear{symbol:'#', part_numbers:vec![12, 3], ratio: BigUint::from(15u32)}));
+    assert!(gears.contains(&Gear{symbol:'=', part_numbers:vec![9],     ratio: BigUint::from(9u32)}));
+    assert_eq!(get_gears_of(&adj, &GearRules::parse("# 3+ sum").unwrap()), []);
+}
+
+
+//////// Rendering
+
+// what a character of the schematic belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Category {
+    Empty,
+    PartNumber,
+    OtherNumber,
+    Gear,
+    OtherSymbol
+}
+
+use Category::*;
+
+const CATEGORIES : [(Category, &str); 4] = [(PartNumber, "part number"), (OtherNumber, "other number"), (Gear, "gear"), (OtherSymbol, "other symbol")];
+
+fn categorize(engi: &EngineSchematic, adj: &AdjacencyGraph, rules: &GearRules) -> Vec<Vec<Category>> {
+    let mut categories:Vec<Vec<Category>> = engi.lines.iter().map(|line| vec![Empty; line.len()]).collect();
+    for (number, symbols) in adj.numbers.iter().zip(&adj.symbols_of_number) {
+        let category = if symbols.is_empty() { OtherNumber } else { PartNumber };
+        categories[number.line][number.from..=number.to].fill(category);
+    }
+    for (symbol_index, symbol) in adj.symbols.iter().enumerate() {
+        let is_gear = gear_rule(adj, symbol_index, rules).is_some();
+        categories[symbol.line][symbol.column] = if is_gear { Gear } else { OtherSymbol };
+    }
+    categories
+}
+
+// split a line into pieces of the same category
+// the line is sliced at byte offsets, get_adjacencies made sure that it is ASCII
+fn split_by_category<'a>(line:&'a str, categories:&[Category]) -> Vec<(Category, &'a str)> {
+    let mut pieces = Vec::new();
+    let mut from = 0;
+    for column in 1..=categories.len() {
+        if column == categories.len() || categories[column] != categories[from] {
+            pieces.push((categories[from], &line[from..column]));
+            from = column;
+        }
+    }
+    pieces
+}
+
+fn ansi_color(category:Category) -> &'static str {
+    match category {
+        Empty       => "90",
+        PartNumber  => "32",
+        OtherNumber => "31",
+        Gear        => "1;33",
+        OtherSymbol => "36"
+    }
+}
+
+fn render_ansi(engi: &EngineSchematic, categories:&[Vec<Category>]) -> String {
+    let mut rendered = String::new();
+    for (line, line_categories) in engi.lines.iter().zip(categories) {
+        for (category, piece) in split_by_category(line, line_categories) {
+            rendered += &format!("\x1b[{}m{}\x1b[0m", ansi_color(category), piece);
+        }
+        rendered += "\n";
+    }
+    let legend:Vec<String> = CATEGORIES.iter()
+        .map(|(category, name)| format!("\x1b[{}m{}\x1b[0m", ansi_color(*category), name))
+        .collect();
+    rendered += &legend.join(" ");
+    rendered += "\n";
+    rendered
+}
+
+fn html_class(category:Category) -> &'static str {
+    match category {
+        Empty       => "empty",
+        PartNumber  => "part",
+        OtherNumber => "other",
+        Gear        => "gear",
+        OtherSymbol => "symbol"
+    }
+}
+
+fn escape_html(text:&str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+// a standalone page, the schematic is a <pre> block
+fn render_html(engi: &EngineSchematic, categories:&[Vec<Category>]) -> String {
+    let mut rendered = String::from(r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Engine schematic</title>
+<style>
+body { background: #101010; color: #e0e0e0; font-family: monospace; }
+.empty { color: #505050; }
+.part { color: #40c040; }
+.other { color: #e04040; }
+.gear { color: #f0d000; font-weight: bold; }
+.symbol { color: #40c0e0; }
+</style>
+</head>
+<body>
+<p>"#);
+    let legend:Vec<String> = CATEGORIES.iter()
+        .map(|(category, name)| format!("<span class=\"{}\">{}</span>", html_class(*category), name))
+        .collect();
+    rendered += &legend.join(" ");
+    rendered += "</p>\n<pre>\n";
+    for (line, line_categories) in engi.lines.iter().zip(categories) {
+        for (category, piece) in split_by_category(line, line_categories) {
+            rendered += &format!("<span class=\"{}\">{}</span>", html_class(category), escape_html(piece));
+        }
+        rendered += "\n";
+    }
+    rendered += "</pre>\n</body>\n</html>\n";
+    rendered
+}
+
+#[test]
+fn test_render() {
+    let engi = EngineSchematic{
+        lines: [
+            String::from("467..114.."),
+            String::from("...*......"),
+            String::from("..35..633."),
+            String::from("......#..."),
+            String::from("617*......"),
+            String::from(".....+.58."),
+            String::from("..592....."),
+            String::from("......755."),
+            String::from("...$.*...."),
+            String::from(".664.598..")
+        ].to_vec()
+    };
+    let adj = get_adjacencies(&engi).unwrap();
+    let categories = categorize(&engi, &adj, &GearRules::default());
+    assert_eq!(categories[0][..6], [PartNumber, PartNumber, PartNumber, Empty, Empty, OtherNumber]);
+    assert_eq!(categories[1][3], Gear);
+    assert_eq!(categories[3][6], OtherSymbol);
+    assert_eq!(split_by_category(&engi.lines[0], &categories[0]),
+               [(PartNumber, "467"), (Empty, ".."), (OtherNumber, "114"), (Empty, "..")]);
+
+    crate::assert_snapshot("day3/render_ansi", &render_ansi(&engi, &categories));
+    crate::assert_snapshot("day3/render_html", &render_html(&engi, &categories));
+
+    let engi = EngineSchematic{ lines: [String::from("1&<2")].to_vec() };
+    let adj = get_adjacencies(&engi).unwrap();
+    let categories = categorize(&engi, &adj, &GearRules::parse("& 1 sum").unwrap());
+    assert_eq!(categories[0], [PartNumber, Gear, OtherSymbol, PartNumber]);
+    assert!(render_html(&engi, &categories).contains(r#"<span class="gear">&amp;</span><span class="symbol">&lt;</span>"#));
+}
+
+// a square schematic with a density of numbers and symbols like the puzzle input
+fn generate_schematic(size:usize, seed:u64) -> EngineSchematic {
+    const SYMBOLS : &[u8] = b"*#+$/@=%&-";
+    let mut state = seed.max(1);
+    let mut next = |bound:u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % bound
+    };
+    let mut lines = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut line = Vec::with_capacity(size);
+        while line.len() < size {
+            match next(100) {
+                0..=5 => {
+                    let digits = cmp::min(1 + next(3) as usize, size - line.len());
+                    for _ in 0..digits {
+                        line.push(b'0' + next(10) as u8);
+                    }
+                    if line.len() < size {
+                        line.push(b'.');
+                    }
+                },
+                6..=9 => line.push(SYMBOLS[next(SYMBOLS.len() as u64) as usize]),
+                _     => line.push(b'.')
+            }
+        }
+        lines.push(String::from_utf8(line).unwrap());
+    }
+    EngineSchematic{ lines }
+}
+
+fn sorted_gears(mut gears:Vec<Gear>) -> Vec<Gear> {
+    gears.sort_by(|gear1, gear2| (&gear1.part_numbers, gear1.symbol).cmp(&(&gear2.part_numbers, gear2.symbol)));
+    gears
+}
+
+#[test]
+fn test_scan_equals_search() {
+    let rules = GearRules::parse("* 2 product, # 3+ sum, any 1 max").unwrap();
+    for seed in [1, 7, 2023] {
+        let engi = generate_schematic(150, seed);
+        let scanned = get_adjacencies(&engi).unwrap();
+        let searched = search_adjacencies(&engi);
+        assert_eq!(scanned.numbers, extract_numbers(&engi));
+        for (number, symbols) in scanned.numbers.iter().zip(&scanned.symbols_of_number) {
+            assert_eq!(!symbols.is_empty(), is_part_number(&engi, number), "{:?}", number);
+        }
+        assert_eq!(sorted_gears(get_gears_of(&scanned, &rules)), sorted_gears(get_gears_of(&searched, &rules)));
+    }
+
+    // narrow schematics have numbers at both borders
+    let engi = EngineSchematic{ lines: ["1*", "2.", ".3", "#4"].iter().map(|line| line.to_string()).collect() };
+    let adj = get_adjacencies(&engi).unwrap();
+    assert_eq!(adj.part_numbers().iter().map(|n| n.value).collect::<Vec<u32>>(), [1, 2, 3, 4]);
+    assert_eq!(adj.symbols_with_numbers(0), Vec::<&Symbol>::new());
+}
+
+// compare the search for symbols around every number with the single pass scan
+fn benchmark(size:usize, rules:&GearRules) {
+    let engi = generate_schematic(size, 2023);
+    println!("Day 3 benchmark: schematic of {}x{}", size, size);
+
+    let start = Instant::now();
+    let numbers = extract_numbers(&engi);
+    let search_part_sum:u64 = numbers.iter()
+        .filter(|number| is_part_number(&engi, number))
+        .map(|number| number.value as u64)
+        .sum();
+    let search_gears = get_gears_of(&search_adjacencies(&engi), rules);
+    let search_duration = start.elapsed();
+
+    let start = Instant::now();
+    let adj = get_adjacencies(&engi).unwrap();
+    let scan_part_sum:u64 = adj.part_numbers().iter().map(|number| number.value as u64).sum();
+    let scan_gears = get_gears_of(&adj, rules);
+    let scan_duration = start.elapsed();
+
+    let search_ratio_sum:BigUint = search_gears.iter().map(|gear| &gear.ratio).sum();
+    let scan_ratio_sum:BigUint = scan_gears.iter().map(|gear| &gear.ratio).sum();
+    println!("       search: part sum {}, {} gears with ratio sum {} in {:?}", search_part_sum, search_gears.len(), search_ratio_sum, search_duration);
+    println!("       scan:   part sum {}, {} gears with ratio sum {} in {:?}", scan_part_sum, scan_gears.len(), scan_ratio_sum, scan_duration);
+    assert_eq!(search_part_sum, scan_part_sum, "Scan and search disagree");
+    assert_eq!(sorted_gears(search_gears), sorted_gears(scan_gears), "Scan and search disagree");
+}
+
+// -----------------------------------------------------------------------------------
+
+use common::runner::{Answers, Options, Solution};
+
+// option "gears=<rules>" replaces the rule of part 2 "any 2 product", e.g. "gears=* 2 product, # 3+ sum"
+// option "render=ansi" prints the schematic in colors, "render=html" writes it to "output=<file>" (default day3.html)
+// option "benchmark" compares the symbol search with the single pass scan
+// on a generated schematic of "size=<columns>" (default 10000) columns and lines
+pub fn part1and2(options:&Options) -> Solution {
+    let rules = match options.get("gears") {
+        Some(text) => GearRules::parse(text)?,
+        None => GearRules::default()
+    };
+
+    if options.is_set("benchmark") {
+        let size = options.get("size").map_or(10_000, |size| size.parse().expect("size must be a number"));
+        benchmark(size, &rules);
+        return Ok(Answers::default());
+    }
+
+    let engi = EngineSchematic{ lines: crate::read_input_lines(3) };
+    let adj = get_adjacencies(&engi)?;
+    let part_numbers = adj.part_numbers();
+    let sum_of_part_numbers:u64 = part_numbers.iter().map(|number| number.value as u64).sum();
+
+    let gears = get_gears_of(&adj, &rules);
+    let mut sum_of_gear_ratio = BigUint::default();
+    let mut cnt_of_gears = 0;
+
+    for gear in gears {
+        sum_of_gear_ratio += gear.ratio;
+        cnt_of_gears += 1;
+    }
+    match options.get("render") {
+        Some("ansi") => print!("{}", render_ansi(&engi, &categorize(&engi, &adj, &rules))),
+        Some("html") => {
+            let path = options.get("output").unwrap_or("day3.html");
+            std::fs::write(path, render_html(&engi, &categorize(&engi, &adj, &rules))).expect("Could not write the rendered schematic");
+            println!("Day 3: Schematic written to {}", path);
+        },
+        Some(other) => println!("Day 3: Unknown rendering {}, use ansi or html", other),
+        None => ()
+    }
+
+    let components = adj.connected_components();
+    println!("Day 3: {} numbers touch no symbol, {} of {} symbols touch no number.",
+      adj.numbers_without_symbol().len(),
+      adj.symbols_with_numbers(0).len(), adj.symbols.len());
+    println!("       The parts form {} connected groups, the largest has {} parts.",
+      components.len(),
+      components.iter().map(|component| component.len()).max().unwrap_or(0));
+
+    Ok(Answers::new(
+        format!("{} of {} numbers are parts. Their sum = {}", part_numbers.len(), adj.numbers.len(), sum_of_part_numbers),
+        format!("{} gears exist, the sum of their ratios is {}", cnt_of_gears, sum_of_gear_ratio)))
+}
@@ -0,0 +1,607 @@
+use std::cmp;
+use std::fmt;
+use std::collections::HashSet;
+use std::time::Instant;
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+#[derive(Clone)]
+struct Card
+{
+    card_index: u32,
+    winning_numbers: Vec<u32>,
+    numbers_you_have: Vec<u32>
+}
+
+//////// Parser
+
+use pest::Parser;
+use pest_derive::Parser;
+use pest::iterators::Pair;
+use pest::error::{Error, ErrorVariant};
+
+#[derive(Parser)]
+#[grammar = "../grammar/day4.pest"]
+struct Day4Parser;
+
+// shows line and column of the problem
+type ParseError = Box<Error<Rule>>;
+
+#[test]
+fn test_parse() {
+    assert!(Day4Parser::parse(Rule::single_card, "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").is_ok());
+    assert!(Day4Parser::parse(Rule::single_card, "Card   12:  1 | 2").is_ok());
+    assert!(Day4Parser::parse(Rule::single_card, "Card 1: 41 48 | ").is_err());
+    assert!(Day4Parser::parse(Rule::single_card, "Card 1: 41 x8 | 3").is_err());
+    assert!(Day4Parser::parse(Rule::file, "Card 1: 4 | 3\r\nCard 2: 5 | 5\r\n").is_ok());
+}
+
+fn error_at(pair:&Pair<'_, Rule>, message:String) -> ParseError {
+    Box::new(Error::new_from_span(ErrorVariant::CustomError { message }, pair.as_span()))
+}
+
+fn build_number(number_rule:&Pair<'_, Rule>, what:&str) -> Result<u32, ParseError> {
+    number_rule.as_str().parse::<u32>()
+        .map_err(|_| error_at(number_rule, format!("{} {} is too large", what, number_rule.as_str())))
+}
+
+fn build_numbers(numbers_rule:Pair<'_, Rule>) -> Result<Vec<u32>, ParseError> {
+    numbers_rule.into_inner().map(|number| build_number(&number, "number")).collect()
+}
+
+#[test]
+fn test_parse_numbers() {
+    let parse_numbers = |input| build_numbers(Day4Parser::parse(Rule::numbers, input).unwrap().next().unwrap()).unwrap();
+    assert_eq!(parse_numbers(" 41 48 83 86 17"), [41, 48, 83, 86, 17].to_vec());
+    assert_eq!(parse_numbers(" 83 86  6 86 17"), [83, 86,  6, 86, 17].to_vec());
+}
+
+fn build_card(card_rule:Pair<'_, Rule>) -> Result<Card, ParseError> {
+    let mut inner = card_rule.into_inner();
+    Ok(Card {
+        card_index: build_number(&inner.next().unwrap(), "card index")?,
+        winning_numbers: build_numbers(inner.next().unwrap())?,
+        numbers_you_have: build_numbers(inner.next().unwrap())?
+    })
+}
+
+#[cfg(test)]
+fn parse_line(input:&str) -> Card {
+    match Day4Parser::parse(Rule::single_card, input) {
+        Ok(mut file) => build_card(file.next().unwrap().into_inner().next().unwrap())
+            .unwrap_or_else(|error| panic!("Could not parse: {}\n{}", input, error)),
+        Err(error) => panic!("Could not parse: {}\n{}", input, error)
+    }
+}
+
+// a card and the line where it was found
+struct NumberedCard {
+    line: usize,
+    card: Card
+}
+
+fn parse_cards(input:&str) -> Result<Vec<NumberedCard>, ParseError> {
+    let file = Day4Parser::parse(Rule::file, input)?.next().unwrap();
+    file.into_inner()
+        .filter(|entry| entry.as_rule() == Rule::card)
+        .map(|card_rule| Ok(NumberedCard{ line:card_rule.line_col().0, card:build_card(card_rule)? }))
+        .collect()
+}
+
+//////// Validation
+
+#[derive(Debug, PartialEq)]
+enum Anomaly {
+    // the first card should have index 1, every further card the index of the previous card plus 1
+    // u64, because the card after index u32::MAX is expected to have index 2^32
+    UnexpectedIndex { expected: u64, found: u32 },
+    DuplicateWinningNumber(u32),
+    DuplicateNumberYouHave(u32),
+    // every card should have as many winning numbers as the first card
+    WinningNumberCount { expected: usize, found: usize }
+}
+
+#[derive(Debug, PartialEq)]
+struct Finding {
+    line: usize,
+    card_index: u32,
+    anomaly: Anomaly
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, card {}: ", self.line, self.card_index)?;
+        match &self.anomaly {
+            Anomaly::UnexpectedIndex{expected, found}    => write!(f, "index {} instead of {}", found, expected),
+            Anomaly::DuplicateWinningNumber(number)      => write!(f, "winning number {} is given twice", number),
+            Anomaly::DuplicateNumberYouHave(number)      => write!(f, "number you have {} is given twice", number),
+            Anomaly::WinningNumberCount{expected, found} => write!(f, "{} winning numbers instead of {}", found, expected)
+        }
+    }
+}
+
+// numbers which appear more than once, each reported once
+fn find_duplicates(numbers:&[u32]) -> Vec<u32> {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+    for number in numbers {
+        if !seen.insert(*number) && !duplicates.contains(number) {
+            duplicates.push(*number);
+        }
+    }
+    duplicates
+}
+
+fn validate_cards(cards:&[NumberedCard]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut expected_index:u64 = 1;
+    let winning_number_count = cards.first().map(|first| first.card.winning_numbers.len());
+    for NumberedCard{line, card} in cards {
+        let mut report = |anomaly| findings.push(Finding{ line:*line, card_index:card.card_index, anomaly });
+        if card.card_index as u64 != expected_index {
+            report(Anomaly::UnexpectedIndex{ expected:expected_index, found:card.card_index });
+        }
+        expected_index = card.card_index as u64 + 1;
+        for number in find_duplicates(&card.winning_numbers) {
+            report(Anomaly::DuplicateWinningNumber(number));
+        }
+        for number in find_duplicates(&card.numbers_you_have) {
+            report(Anomaly::DuplicateNumberYouHave(number));
+        }
+        if let Some(expected) = winning_number_count {
+            if card.winning_numbers.len() != expected {
+                report(Anomaly::WinningNumberCount{ expected, found:card.winning_numbers.len() });
+            }
+        }
+    }
+    findings
+}
+
+#[test]
+fn test_validate_cards() {
+    let input = "Card 1: 41 48 83 | 83 86  6 31
+Card 2: 13 32 20 | 61 30 68 82
+Card 4:  1 21  1 | 69 82 63 82
+Card 5: 41 92 | 59 84 76 51
+Card 5: 87 83 26 | 88 30 70 12
+";
+    let cards = parse_cards(input).unwrap();
+    assert_eq!(cards.iter().map(|card| card.line).collect::<Vec<usize>>(), [1, 2, 3, 4, 5]);
+    let findings = validate_cards(&cards);
+    assert_eq!(findings, [
+        Finding{ line:3, card_index:4, anomaly:Anomaly::UnexpectedIndex{ expected:3, found:4 } },
+        Finding{ line:3, card_index:4, anomaly:Anomaly::DuplicateWinningNumber(1) },
+        Finding{ line:3, card_index:4, anomaly:Anomaly::DuplicateNumberYouHave(82) },
+        Finding{ line:4, card_index:5, anomaly:Anomaly::WinningNumberCount{ expected:3, found:2 } },
+        Finding{ line:5, card_index:5, anomaly:Anomaly::UnexpectedIndex{ expected:6, found:5 } }]);
+    assert_eq!(findings[0].to_string(), "line 3, card 4: index 4 instead of 3");
+    assert_eq!(findings[3].to_string(), "line 4, card 5: 2 winning numbers instead of 3");
+
+    let valid = "Card 1: 41 48 83 | 83 86  6 31\nCard 2: 13 32 20 | 61 30 68 82";
+    assert_eq!(validate_cards(&parse_cards(valid).unwrap()), []);
+
+    let last = "Card 4294967295: 1 | 1\nCard 1: 2 | 2";
+    assert_eq!(validate_cards(&parse_cards(last).unwrap()), [
+        Finding{ line:1, card_index:u32::MAX, anomaly:Anomaly::UnexpectedIndex{ expected:1, found:u32::MAX } },
+        Finding{ line:2, card_index:1, anomaly:Anomaly::UnexpectedIndex{ expected:1 << 32, found:1 } }]);
+
+    let error = parse_cards("Card 1: 41 48 83 | 83 86\nCard 2: 13 32 20 - 61 30\n").err().unwrap();
+    assert_eq!(error.line_col, pest::error::LineColLocation::Pos((2, 18)));
+
+    let error = parse_cards("Card 1: 99999999999 | 1\n").err().unwrap();
+    assert_eq!(error.line_col, pest::error::LineColLocation::Span((1, 9), (1, 20)));
+    assert!(error.to_string().contains("number 99999999999 is too large"));
+    let error = parse_cards("Card 1: 4 | 3\nCard 99999999999: 5 | 5\n").err().unwrap();
+    assert_eq!(error.line_col, pest::error::LineColLocation::Span((2, 6), (2, 17)));
+    assert!(error.to_string().contains("card index 99999999999 is too large"));
+}
+
+// 1 for the first match, doubled for every further match
+// None if the worth does not fit into u64 (65 or more matches)
+fn calculate_card_worth(card:&Card) -> Option<u64> {
+    match count_winning_cards(card) {
+        0     => Some(0),
+        count => 1u64.checked_shl(count - 1)
+    }
+}
+
+fn count_winning_cards(card:&Card) -> u32 {
+  let winning_numbers:HashSet<u32> = card.winning_numbers.iter().copied().collect();
+  card.numbers_you_have.iter().filter(|my_number| winning_numbers.contains(my_number)).count() as u32
+}
+
+#[test]
+fn test_example1() {
+
+    let card1 = parse_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
+    let card2 = parse_line("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19");
+    let card3 = parse_line("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1");
+    let card4 = parse_line("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83");
+    let card5 = parse_line("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36");
+    let card6 = parse_line("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11");
+
+    assert_eq!(&card1,
+      &Card{
+        card_index: 1,
+        winning_numbers: [41, 48, 83, 86, 17].to_vec(),
+        numbers_you_have: [83, 86, 6, 31, 17, 9, 48, 53].to_vec()
+      }
+    );
+    assert_eq!(&card2,
+      &Card{
+        card_index: 2,
+        winning_numbers: [13, 32, 20, 16, 61].to_vec(),
+        numbers_you_have: [61, 30, 68, 82, 17, 32, 24, 19].to_vec()
+      }
+    );
+    assert_eq!(&card3,
+      &Card{
+        card_index: 3,
+        winning_numbers: [1, 21, 53, 59, 44].to_vec(),
+        numbers_you_have: [69, 82, 63, 72, 16, 21, 14, 1].to_vec()
+      }
+    );
+    assert_eq!(&card4,
+      &Card{
+        card_index: 4,
+        winning_numbers: [41, 92, 73, 84, 69].to_vec(),
+        numbers_you_have: [59, 84, 76, 51, 58, 5, 54, 83].to_vec()
+      }
+    );
+    assert_eq!(&card5,
+      &Card{
+        card_index: 5,
+        winning_numbers: [87, 83, 26, 28, 32].to_vec(),
+        numbers_you_have: [88, 30, 70, 12, 93, 22, 82, 36].to_vec()
+      }
+    );
+    assert_eq!(&card6,
+      &Card{
+        card_index: 6,
+        winning_numbers: [31, 18, 13, 56, 72].to_vec(),
+        numbers_you_have: [74, 77, 10, 23, 35, 67, 36, 11].to_vec()
+      }
+    );
+
+    assert_eq!(calculate_card_worth(&card1), Some(8));
+    assert_eq!(calculate_card_worth(&card2), Some(2));
+    assert_eq!(calculate_card_worth(&card3), Some(2));
+    assert_eq!(calculate_card_worth(&card4), Some(1));
+    assert_eq!(calculate_card_worth(&card5), Some(0));
+    assert_eq!(calculate_card_worth(&card6), Some(0));
+
+    let lucky_card = Card{ card_index:7, winning_numbers:(1..=40).collect(), numbers_you_have:(1..=40).collect() };
+    assert_eq!(count_winning_cards(&lucky_card), 40);
+    assert_eq!(calculate_card_worth(&lucky_card), Some(1 << 39));
+    let luckier_card = Card{ card_index:8, winning_numbers:(1..=65).collect(), numbers_you_have:(1..=65).collect() };
+    assert_eq!(calculate_card_worth(&luckier_card), None);
+    let deck = CardDeck{ cards:vec![CardInstances{ card:luckier_card, instances:1 }] };
+    assert_eq!(simulate(deck, &ScoringRules::default()).err(), Some("Points of card 8 do not fit into u64".to_string()));
+}
+
+// Multiple instances of one card
+struct CardInstances {
+  card: Card,
+  instances: u64
+
+}
+
+struct CardDeck {
+  // how many instances do I have for these cards?
+  cards:Vec<CardInstances>
+}
+
+#[cfg(test)]
+fn parse_deck(lines:Vec<&str>) -> CardDeck {
+  let mut card_deck = CardDeck{cards:Vec::new()};
+  for line in lines {
+    let card = parse_line(&line);
+    card_deck.cards.push(CardInstances{card:card, instances:1});
+  }
+  card_deck
+}
+
+fn play_deck(deck:CardDeck) -> Result<CardDeck, String> {
+  play_deck_with_copies(deck, &Copies::Next)
+}
+
+// every instance of a card wins one copy of each of the following cards,
+// so the copies of a card are the sum of the instances of all cards whose win reaches it.
+// this sum is kept running: instances are added when a win starts
+// and taken away again at the card after the last won one.
+// when the previous cards are won, the deck is played from the last card to the first
+fn play_deck_with_copies(mut deck:CardDeck, won_cards:&Copies) -> Result<CardDeck, String> {
+  let card_count = deck.cards.len();
+  let (backward, cap) = match won_cards {
+    Copies::Next            => (false, usize::MAX),
+    Copies::Previous        => (true,  usize::MAX),
+    Copies::NextCapped(cap) => (false, *cap)
+  };
+  let mut copies:u64 = 0;
+  let mut expiring_copies = vec![0u64; card_count + 1];
+  for step in 0..card_count {
+    copies -= expiring_copies[step];
+    let current_index = if backward { card_count - 1 - step } else { step };
+    let current_card_instances = &mut deck.cards[current_index];
+    let current_instances = current_card_instances.instances.checked_add(copies)
+      .ok_or(format!("Instances of card {} do not fit into u64", current_card_instances.card.card_index))?;
+    current_card_instances.instances = current_instances;
+
+    let winning_cards = cmp::min(count_winning_cards(&current_card_instances.card) as usize, cap);
+    let end_step = cmp::min(step + 1 + winning_cards, card_count);
+    if end_step > step + 1 {
+      copies = copies.checked_add(current_instances)
+        .ok_or(format!("Copies won by card {} do not fit into u64", current_card_instances.card.card_index))?;
+      expiring_copies[end_step] += current_instances;
+    }
+  }
+  Ok(deck)
+}
+
+// None if the sum does not fit into u64
+fn count_all_cards_in_deck(deck:&CardDeck) -> Option<u64> {
+  deck.cards.iter().try_fold(0u64, |count, card_instances| count.checked_add(card_instances.instances))
+}
+
+#[test]
+fn test_example2() {
+
+    let lines = [ "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+                  "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+                  "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+                  "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+                  "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+                  "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"].to_vec();
+
+    let initial_deck = parse_deck(lines);
+
+    assert_eq!(initial_deck.cards.len(), 6);
+    assert_eq!(initial_deck.cards[2].card.card_index, 3);
+    assert_eq!(initial_deck.cards[2].instances, 1);
+    assert_eq!(count_all_cards_in_deck(&initial_deck), Some(6));
+
+    let played_deck = play_deck(initial_deck).unwrap();
+    assert_eq!(played_deck.cards[0].instances, 1);
+    assert_eq!(count_winning_cards(&played_deck.cards[0].card), 4);
+    assert_eq!(played_deck.cards[1].instances, 2);
+    assert_eq!(count_winning_cards(&played_deck.cards[1].card), 2);
+    assert_eq!(played_deck.cards[2].instances, 4);
+    assert_eq!(count_winning_cards(&played_deck.cards[2].card), 2);
+    assert_eq!(played_deck.cards[3].instances, 8);
+    assert_eq!(count_winning_cards(&played_deck.cards[3].card), 1);
+    assert_eq!(played_deck.cards[4].instances, 14);
+    assert_eq!(count_winning_cards(&played_deck.cards[4].card), 0);
+    assert_eq!(played_deck.cards[5].instances, 1);
+    assert_eq!(count_winning_cards(&played_deck.cards[5].card), 0);
+    assert_eq!(count_all_cards_in_deck(&played_deck), Some(30));
+}
+
+//////// Scoring rules
+
+// how the matches of a card give points
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Points {
+    // 1 for the first match, doubled for every further match
+    Doubling,
+    // 1 for every match
+    Linear
+}
+
+impl Points {
+    fn of(&self, card:&Card) -> Option<u64> {
+        match self {
+            Points::Doubling => calculate_card_worth(card),
+            Points::Linear   => Some(count_winning_cards(card) as u64)
+        }
+    }
+}
+
+// which cards are won as copies, one for every match
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Copies {
+    Next,
+    Previous,
+    // the next cards, but at most this many
+    NextCapped(usize)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScoringRules {
+    points: Points,
+    copies: Copies
+}
+
+// the rules of the puzzle
+impl Default for ScoringRules {
+    fn default() -> Self {
+        ScoringRules{ points:Points::Doubling, copies:Copies::Next }
+    }
+}
+
+impl ScoringRules {
+    // points "doubling" or "linear", copies "next", "previous" or "next:<cap>"
+    fn parse(points:Option<&str>, copies:Option<&str>) -> Result<ScoringRules, String> {
+        let points = match points {
+            None | Some("doubling") => Points::Doubling,
+            Some("linear")          => Points::Linear,
+            Some(other)             => return Err(format!("Unknown points {}, use doubling or linear", other))
+        };
+        let copies = match copies {
+            None | Some("next") => Copies::Next,
+            Some("previous")    => Copies::Previous,
+            Some(other) => match other.strip_prefix("next:").map(|cap| cap.parse::<usize>()) {
+                Some(Ok(cap)) => Copies::NextCapped(cap),
+                _             => return Err(format!("Unknown copies {}, use next, previous or next:<cap>", other))
+            }
+        };
+        Ok(ScoringRules{points, copies})
+    }
+}
+
+// a deck played under some rules
+struct Simulation {
+    deck: CardDeck,
+    // points of every original card and in total
+    card_points: Vec<u64>,
+    points: u64,
+    // original and copied cards
+    cards: u64
+}
+
+fn simulate(deck:CardDeck, rules:&ScoringRules) -> Result<Simulation, String> {
+    let card_points = deck.cards.iter()
+        .map(|card_instances| rules.points.of(&card_instances.card).ok_or(format!("Points of card {} do not fit into u64", card_instances.card.card_index)))
+        .collect::<Result<Vec<u64>, String>>()?;
+    let points = card_points.iter()
+        .try_fold(0u64, |points, card_points| points.checked_add(*card_points))
+        .ok_or("Points do not fit into u64")?;
+    let deck = play_deck_with_copies(deck, &rules.copies)?;
+    let cards = count_all_cards_in_deck(&deck).ok_or("Number of cards does not fit into u64")?;
+    Ok(Simulation{ deck, card_points, points, cards })
+}
+
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (card_instances, card_points) in self.deck.cards.iter().zip(&self.card_points) {
+            writeln!(f, "Card {:>4}: {:>2} matches, {:>4} points, {:>10} instances",
+                card_instances.card.card_index,
+                count_winning_cards(&card_instances.card),
+                card_points,
+                card_instances.instances)?;
+        }
+        writeln!(f, "Total: {} points, {} cards", self.points, self.cards)
+    }
+}
+
+#[test]
+fn test_scoring_rules() {
+    let lines = [ "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+                  "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+                  "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+                  "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+                  "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+                  "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"].to_vec();
+    let instances = |simulation:&Simulation| simulation.deck.cards.iter().map(|card_instances| card_instances.instances).collect::<Vec<u64>>();
+
+    let puzzle = simulate(parse_deck(lines.clone()), &ScoringRules::default()).unwrap();
+    assert_eq!((puzzle.points, puzzle.cards), (13, 30));
+    assert_eq!(instances(&puzzle), [1, 2, 4, 8, 14, 1]);
+
+    let linear_previous = ScoringRules::parse(Some("linear"), Some("previous")).unwrap();
+    assert_eq!(linear_previous, ScoringRules{ points:Points::Linear, copies:Copies::Previous });
+    let simulation = simulate(parse_deck(lines.clone()), &linear_previous).unwrap();
+    assert_eq!((simulation.points, simulation.cards), (9, 14));
+    assert_eq!(instances(&simulation), [6, 3, 2, 1, 1, 1]);
+
+    let capped = ScoringRules::parse(None, Some("next:1")).unwrap();
+    let simulation = simulate(parse_deck(lines.clone()), &capped).unwrap();
+    assert_eq!((simulation.points, simulation.cards), (13, 16));
+    assert_eq!(instances(&simulation), [1, 2, 3, 4, 5, 1]);
+    assert_eq!(simulation.to_string().lines().nth(2).unwrap(), "Card    3:  2 matches,    2 points,          3 instances");
+    assert_eq!(simulation.to_string().lines().last().unwrap(), "Total: 13 points, 16 cards");
+
+    assert!(ScoringRules::parse(Some("tripling"), None).is_err());
+    assert!(ScoringRules::parse(None, Some("next:x")).is_err());
+}
+
+// cards with 10 winning numbers and 25 numbers you have,
+// each winning number is one of yours with a chance of win_percent percent
+fn generate_deck(cards:usize, win_percent:u64, seed:u64) -> CardDeck {
+    let mut state = seed.max(1);
+    let mut next = |bound:u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % bound
+    };
+    let mut deck = CardDeck{cards:Vec::with_capacity(cards)};
+    for card_index in 1..=cards {
+        // winning numbers are 1..=49, the others 50..=99, so that matches are only made on purpose
+        let winning_numbers:Vec<u32> = (0..10).map(|_| 1 + next(49) as u32).collect();
+        let mut numbers_you_have:Vec<u32> = (0..25).map(|_| 50 + next(50) as u32).collect();
+        for (slot, winning_number) in winning_numbers.iter().enumerate() {
+            if next(100) < win_percent {
+                numbers_you_have[slot] = *winning_number;
+            }
+        }
+        let card = Card{ card_index:card_index as u32, winning_numbers, numbers_you_have };
+        deck.cards.push(CardInstances{card, instances:1});
+    }
+    deck
+}
+
+#[test]
+fn test_play_generated_deck() {
+    // compare with adding the copies card by card
+    let deck = generate_deck(2000, 10, 4);
+    let mut instances = vec![1u64; deck.cards.len()];
+    for (index, card_instances) in deck.cards.iter().enumerate() {
+        let winning_cards = count_winning_cards(&card_instances.card) as usize;
+        for win_index in index + 1 .. cmp::min(index + 1 + winning_cards, deck.cards.len()) {
+            instances[win_index] += instances[index];
+        }
+    }
+    let played_deck = play_deck(deck).unwrap();
+    let played_instances:Vec<u64> = played_deck.cards.iter().map(|card_instances| card_instances.instances).collect();
+    assert_eq!(played_instances, instances);
+    assert_eq!(count_all_cards_in_deck(&played_deck), Some(instances.iter().sum()));
+
+    // every card wins the next 10 cards: the copies grow too fast
+    let error = play_deck(generate_deck(200, 100, 4)).err().unwrap();
+    assert!(error.contains("do not fit into u64"), "{}", error);
+}
+
+// play generated decks with few and with many wins per card
+fn demonstrate(cards:usize) {
+    for win_percent in [8, 30] {
+        let deck = generate_deck(cards, win_percent, 2023);
+        let start = Instant::now();
+        let result = play_deck(deck);
+        let duration = start.elapsed();
+        match result.map(|played_deck| count_all_cards_in_deck(&played_deck)) {
+            Ok(Some(count)) => println!("Day 4: {} generated cards with {}% wins: {} cards in total, played in {:?}", cards, win_percent, count, duration),
+            Ok(None)        => println!("Day 4: {} generated cards with {}% wins: total does not fit into u64, played in {:?}", cards, win_percent, duration),
+            Err(message)    => println!("Day 4: {} generated cards with {}% wins: {} (after {:?})", cards, win_percent, message, duration)
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------------
+
+use common::runner::{Answers, Options, Solution};
+
+// option "generate=<cards>" plays generated decks instead of the puzzle input
+// options "points=<doubling|linear>" and "copies=<next|previous|next:cap>" change the rules,
+// option "report" shows the instances of every card
+pub fn part1and2(options:&Options) -> Solution {
+    if let Some(cards) = options.get("generate") {
+        demonstrate(cards.parse().expect("generate needs a number of cards"));
+        return Ok(Answers::default());
+    }
+
+    let cards = match parse_cards(&crate::read_input(4)) {
+        Ok(cards) => cards,
+        Err(error) => return Err(format!("invalid input\n{}", error))
+    };
+    let findings = validate_cards(&cards);
+    if !findings.is_empty() {
+        println!("Day 4: {} anomalies in the deck", findings.len());
+        for finding in &findings {
+            println!("       {}", finding);
+        }
+    }
+
+    let rules = ScoringRules::parse(options.get("points"), options.get("copies"))?;
+    let cnt_cards = cards.len();
+    let initial_deck = CardDeck{ cards: cards.into_iter().map(|numbered| CardInstances{card:numbered.card, instances:1}).collect() };
+
+    // part 1 and 2
+    let simulation = simulate(initial_deck, &rules)?;
+    if options.is_set("report") {
+        print!("{}", simulation);
+    }
+
+    Ok(Answers::new(
+        format!("{} cards with a worth sum of {}", cnt_cards, simulation.points),
+        format!("In total you have {} cards (original and copied)", simulation.cards)))
+}
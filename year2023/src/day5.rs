@@ -0,0 +1,1579 @@
+use pest::Parser;
+use pest_derive::Parser;
+
+#[derive(Parser)]
+#[grammar = "../grammar/day5.pest"]
+struct Day5Parser;
+
+#[test]
+fn test_parse() {
+    let parse1 = Day5Parser::parse(Rule::number, "42").unwrap().peek().unwrap();
+    assert_eq!(parse1.as_rule(), Rule::number);
+    assert_eq!(parse1.as_str(), "42");
+
+    assert_eq!(Day5Parser::parse(Rule::number, "6").unwrap().as_str(), "6");
+
+    assert_eq!(Day5Parser::parse(Rule::seeds, "seeds: 4 15 76").unwrap().as_str(), "seeds: 4 15 76");
+
+    assert_eq!(Day5Parser::parse(Rule::list_of_triples, "4 15 76\n1 2 3\n22 23 24").unwrap().as_str(), "4 15 76\n1 2 3\n22 23 24");
+
+    assert_eq!(Day5Parser::parse(Rule::category_map, "seed-to-soil map:\n50 98 2").unwrap().as_str(), "seed-to-soil map:\n50 98 2");
+    assert!(Day5Parser::parse(Rule::category_map, "seed-to-Soil map:\n50 98 2").is_err());
+
+}
+
+trait AlmanacType: Copy+Ord{
+    fn to_u64(&self) -> u64;
+    fn from_u64(value:u64) -> Self;
+}
+
+// a value of any category, when the categories come from the input
+#[derive(PartialEq,Debug,Clone,Copy,Eq,PartialOrd,Ord)]
+struct Value(u64);
+
+impl AlmanacType for Value {
+    fn to_u64(&self) -> u64 { self.0 }
+    fn from_u64(value:u64) -> Self { Self(value )}
+}
+
+#[derive(Debug,Clone,Copy)]
+struct MappingRange<Destination:AlmanacType, Source:AlmanacType> {
+    destination_range_start: Destination,
+    source_range_start: Source,
+    range_length: u64
+}
+
+use std::cmp::min;
+use std::cmp::max;
+
+#[derive(PartialEq,Debug)]
+struct MappingRangeConversionResult<Destination:AlmanacType, Source:AlmanacType> {
+    before: Option<Range<Source>>,
+    mapped: Option<Range<Destination>>,
+    behind: Option<Range<Source>>
+}
+
+impl<Destination:AlmanacType, Source:AlmanacType> MappingRange<Destination, Source> {
+
+    fn is_source_in_range(&self, source:Source) -> bool {
+        source >= self.source_range_start
+        &&
+        source < Source::from_u64(self.source_range_start.to_u64() + self.range_length)
+    }
+
+    fn convert(&self, source:Source) -> Destination {
+        Destination::from_u64(
+            self.destination_range_start.to_u64() +
+            ( source.to_u64() - self.source_range_start.to_u64() ))
+    }
+
+    fn source_range(&self) -> Range<Source> {
+        self.source_range_start .. Source::from_u64(self.source_range_start.to_u64() + self.range_length)
+    }
+
+    fn destination_range(&self) -> Range<Destination> {
+        self.destination_range_start .. Destination::from_u64(self.destination_range_start.to_u64() + self.range_length)
+    }
+
+    // convert a..b according to mapping range
+    // result can be up to three ranges:
+    //           |----MappingSourceRange--|
+    //   |-------------sourceRange------------------|
+    //   |-before|                        |-behind--|     |---destinationRange---|
+    fn convert_range(&self, source_range:&Range<Source>) -> MappingRangeConversionResult<Destination, Source> {
+        let mapping_start = self.source_range_start;
+        let mapping_end = Source::from_u64(self.source_range_start.to_u64() + self.range_length);
+        MappingRangeConversionResult {
+            before: if source_range.start < mapping_start { Some(source_range.start .. min(source_range.end, mapping_start))} else { None },
+            behind: if source_range.end   > mapping_end   { Some(max(source_range.start, mapping_end) .. source_range.end)} else { None },
+            mapped: if source_range.start < mapping_end && source_range.end >= mapping_start
+                      { Some(self.convert(max(source_range.start, mapping_start)) .. self.convert(min(source_range.end, mapping_end))) }
+                    else { None }
+        }
+    }
+
+}
+
+#[test]
+fn test_mapping_range() {
+    let range = MappingRange{
+        destination_range_start: Value(50),
+        source_range_start: Value(98),
+        range_length: 2};
+    assert!(!range.is_source_in_range(Value( 97)));
+    assert!( range.is_source_in_range(Value( 98)));
+    assert!( range.is_source_in_range(Value( 99)));
+    assert!(!range.is_source_in_range(Value(100)));
+
+    assert_eq!(range.convert(Value(98)), Value(50));
+    assert_eq!(range.convert(Value(99)), Value(51));
+
+    assert_eq!(range.convert_range(&(Value(50)..Value(55))),
+      MappingRangeConversionResult {
+        before: Some(Value(50)..Value(55)),
+        mapped: None,
+        behind: None
+      });
+
+      assert_eq!(range.convert_range(&(Value(150)..Value(155))),
+      MappingRangeConversionResult {
+        before: None,
+        mapped: None,
+        behind: Some(Value(150)..Value(155))
+      });
+
+      assert_eq!(range.convert_range(&(Value(98)..Value(100))),
+      MappingRangeConversionResult {
+        before: None,
+        mapped: Some(Value(50)..Value(52)),
+        behind: None
+      });
+
+      assert_eq!(range.convert_range(&(Value(90)..Value(105))),
+      MappingRangeConversionResult {
+        before: Some(Value(90)..Value(98)),
+        mapped: Some(Value(50)..Value(52)),
+        behind: Some(Value(100)..Value(105))
+      });
+
+}
+
+
+use std::ops::Range;
+// A list of ranges, e.g. [3..5, 7..9, 11..12] = [3,4,7,8,11]
+#[derive(Clone,Debug)]
+struct RangeList<T:AlmanacType> {
+    ranges: Vec<Range<T>>
+}
+
+impl<T:AlmanacType> RangeList<T> {
+    // create single-valued ranges: [3,5,11] -> [3..4, 5..6, 11..12]
+    fn create_single_valued_ranges(single_values: &Vec<T>) -> Self {
+        let mut vec:Vec<Range<T>> = Vec::new();
+        for t in single_values {
+            vec.push(*t .. T::from_u64(t.to_u64()+1));
+        }
+        Self { ranges: vec }
+    }
+
+    // create real ranges: [(3,5),(7,9),(11,12)] -> [3..5, 7..9, 11..12]
+    fn create_real_ranges(ranges: &Vec<Range<T>>) -> Self {
+        Self { ranges: ranges.clone() }
+    }
+
+    fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    #[cfg(test)]
+    fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    // all values, range by range
+    #[cfg(test)]
+    fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(|range| (range.start.to_u64()..range.end.to_u64()).map(T::from_u64))
+    }
+
+    // sorted, without empty ranges, overlapping or adjacent ranges merged:
+    // [8..12, 4..5, 11..13, 5..6, 7..7] -> [4..6, 8..13]
+    fn normalized(&self) -> Self {
+        let mut ranges:Vec<Range<T>> = self.ranges.iter().filter(|range| range.start < range.end).cloned().collect();
+        ranges.sort_by_key(|range| range.start);
+        let mut merged:Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = max(last.end, range.end),
+                _ => merged.push(range)
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    #[cfg(test)]
+    fn is_normalized(&self) -> bool {
+        self.ranges.iter().all(|range| range.start < range.end)
+        &&
+        self.ranges.windows(2).all(|pair| pair[0].end < pair[1].start)
+    }
+
+    #[cfg(test)]
+    fn union(&self, other:&Self) -> Self {
+        Self { ranges: self.ranges.iter().chain(&other.ranges).cloned().collect() }.normalized()
+    }
+
+    fn intersection(&self, other:&Self) -> Self {
+        let (left, right) = (self.normalized().ranges, other.normalized().ranges);
+        let mut ranges = Vec::new();
+        let (mut l, mut r) = (0, 0);
+        while l < left.len() && r < right.len() {
+            let start = max(left[l].start, right[r].start);
+            let end = min(left[l].end, right[r].end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // the range which ends first cannot overlap with anything else
+            if left[l].end < right[r].end { l += 1; } else { r += 1; }
+        }
+        Self { ranges }
+    }
+
+    // all values of self which are not in other
+    #[cfg(test)]
+    fn difference(&self, other:&Self) -> Self {
+        let (left, right) = (self.normalized().ranges, other.normalized().ranges);
+        let mut ranges = Vec::new();
+        let mut first_right = 0;
+        for range in left {
+            // right ranges before this range are before all further ranges, too
+            while first_right < right.len() && right[first_right].end <= range.start {
+                first_right += 1;
+            }
+            let mut start = range.start;
+            for cut in right[first_right..].iter().take_while(|cut| cut.start < range.end) {
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = max(start, cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    #[cfg(test)]
+    fn contains(&self, value:T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    #[cfg(test)]
+    fn contains_all(&self, other:&Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    // number of different values
+    #[cfg(test)]
+    fn len(&self) -> u64 {
+        self.normalized().ranges.iter().map(|range| range.end.to_u64() - range.start.to_u64()).sum()
+    }
+
+    #[cfg(test)]
+    fn is_empty(&self) -> bool {
+        self.ranges.iter().all(|range| range.start >= range.end)
+    }
+
+    #[cfg(test)]
+    fn to_sorted_vec(&self) -> Vec<T> {
+        let mut vec = self.to_vec();
+        vec.sort_unstable();
+        vec
+    }
+
+    fn min(&self) -> Option<T> {
+        let mut current_min = None;
+        for range in &self.ranges {
+            current_min = match current_min {
+              None => Some(range.start),
+              Some(current) => Some(min(current, range.start))
+            }
+//            if current_min.is_none() {
+//                current_min = Some(range.start);
+//            } else {
+//                current_min = Some(min(current_min, range.start));
+//            }
+        }
+
+        current_min
+    }
+
+}
+
+#[test]
+fn test_range_list() {
+    let range_list1 = RangeList::create_single_valued_ranges(&[Value(3), Value(5), Value(11)].to_vec());
+    assert_eq!(range_list1.ranges, vec![Value(3)..Value(4), Value(5)..Value(6), Value(11)..Value(12)]);
+    assert_eq!(range_list1.to_vec(), vec![Value(3), Value(5), Value(11)]);
+
+    let range_list2 = RangeList::create_real_ranges(&[Value(3)..Value(5), Value(7)..Value(9), Value(11)..Value(12)].to_vec());
+    assert_eq!(range_list2.ranges, vec![Value(3)..Value(5), Value(7)..Value(9), Value(11)..Value(12)]);
+    assert_eq!(range_list2.to_vec(), vec![Value(3),Value(4), Value(7), Value(8), Value(11)]);
+
+    let range_list3 = RangeList::create_real_ranges(&[Value(8)..Value(12), Value(4)..Value(5), Value(11)..Value(12)].to_vec());
+    assert_eq!(range_list3.min().unwrap(), Value(4));
+}
+
+#[test]
+fn test_range_list_algebra() {
+    let seeds = |ranges:&[(u64, u64)]| RangeList::create_real_ranges(&ranges.iter().map(|(start, end)| Value(*start)..Value(*end)).collect());
+
+    let messy = seeds(&[(8, 12), (4, 5), (11, 13), (5, 6), (7, 7)]);
+    assert!(!messy.is_normalized());
+    assert_eq!(messy.len(), 7);
+    let messy = messy.normalized();
+    assert!(messy.is_normalized());
+    assert_eq!(messy.ranges, seeds(&[(4, 6), (8, 13)]).ranges);
+    assert_eq!(messy.to_vec(), vec![Value(4), Value(5), Value(8), Value(9), Value(10), Value(11), Value(12)]);
+
+    let left = seeds(&[(0, 10), (20, 30)]);
+    let right = seeds(&[(5, 8), (9, 22), (28, 40)]);
+    assert_eq!(left.union(&right).ranges, seeds(&[(0, 40)]).ranges);
+    assert_eq!(left.intersection(&right).ranges, seeds(&[(5, 8), (9, 10), (20, 22), (28, 30)]).ranges);
+    assert_eq!(left.difference(&right).ranges, seeds(&[(0, 5), (8, 9), (22, 28)]).ranges);
+    assert_eq!(right.difference(&left).ranges, seeds(&[(10, 20), (30, 40)]).ranges);
+    assert_eq!(left.difference(&left).ranges, seeds(&[]).ranges);
+    assert!(left.difference(&left).is_empty());
+    assert_eq!(left.intersection(&seeds(&[])).ranges, seeds(&[]).ranges);
+
+    assert!(left.contains(Value(0)));
+    assert!(left.contains(Value(29)));
+    assert!(!left.contains(Value(10)));
+    assert!(left.contains_all(&seeds(&[(1, 3), (21, 30)])));
+    assert!(!left.contains_all(&seeds(&[(1, 3), (21, 31)])));
+    assert!(left.union(&right).contains_all(&left));
+
+    // the algebra agrees with the values
+    let values = |list:&RangeList<Value>| list.to_sorted_vec();
+    let (left_values, right_values) = (values(&left), values(&right));
+    assert_eq!(values(&left.intersection(&right)), left_values.iter().filter(|v| right_values.contains(v)).cloned().collect::<Vec<Value>>());
+    assert_eq!(values(&left.difference(&right)), left_values.iter().filter(|v| !right_values.contains(v)).cloned().collect::<Vec<Value>>());
+    assert_eq!(left.union(&right).len() + left.intersection(&right).len(), left.len() + right.len());
+}
+
+
+#[derive(Debug)]
+struct SourceToDestinationMap<Source:AlmanacType, Destination:AlmanacType> {
+    // as given in the almanac
+    mapping_range_list:Vec<MappingRange<Destination, Source>>,
+    // the parts of the mapping ranges which are applied, sorted by source and without overlaps (the first mapping range wins):
+    // a value is found by binary search, a sorted range list is converted in a single sweep
+    sorted_range_list:Vec<MappingRange<Destination, Source>>
+}
+
+impl<Source:AlmanacType, Destination:AlmanacType> SourceToDestinationMap<Source, Destination> {
+    fn new() -> Self {
+        SourceToDestinationMap { mapping_range_list:Vec::new(), sorted_range_list:Vec::new() }
+    }
+    fn add_range(&mut self, source_range_start: Source, destination_range_start: Destination, range_length: u64) -> () {
+        self.mapping_range_list.push(MappingRange {
+            source_range_start : source_range_start,
+            destination_range_start : destination_range_start,
+            range_length : range_length
+        });
+
+        // only the gaps between the sorted ranges are left for the new mapping range
+        let source_end = source_range_start.to_u64() + range_length;
+        let mut start = source_range_start.to_u64();
+        let mut index = self.sorted_range_list.partition_point(|sorted_range| sorted_range.source_range().end.to_u64() <= start);
+        while start < source_end {
+            match self.sorted_range_list.get(index) {
+                Some(sorted_range) if sorted_range.source_range_start.to_u64() <= start => {
+                    start = sorted_range.source_range().end.to_u64();
+                }
+                next_range => {
+                    let end = next_range.map_or(source_end, |next_range| min(source_end, next_range.source_range_start.to_u64()));
+                    self.sorted_range_list.insert(index, MappingRange {
+                        source_range_start: Source::from_u64(start),
+                        destination_range_start: Destination::from_u64(destination_range_start.to_u64() + (start - source_range_start.to_u64())),
+                        range_length: end - start });
+                    start = end;
+                }
+            }
+            index += 1;
+        }
+    }
+
+    fn convert(&self, source:Source) -> Destination {
+        let index = self.sorted_range_list.partition_point(|sorted_range| sorted_range.source_range().end <= source);
+        match self.sorted_range_list.get(index) {
+            Some(sorted_range) if sorted_range.source_range_start <= source => sorted_range.convert(source),
+            _ => Destination::from_u64(source.to_u64())
+        }
+    }
+
+    // how every part of the source ranges is converted
+    fn explain(&self, source_range_list:&RangeList<Source>) -> Vec<ConversionStep<Source, Destination>> {
+        // (source range, the part of it which is not yet converted)
+        let mut ranges_to_convert:Vec<(Range<Source>, Range<Source>)> =
+            source_range_list.ranges.iter().map(|range| (range.clone(), range.clone())).collect();
+        let mut ranges_to_convert_next = Vec::new();
+        let mut steps = Vec::new();
+        for mapping_range in &self.mapping_range_list {
+            for (input, part) in &ranges_to_convert {
+                let conversion_result = mapping_range.convert_range(part);
+                if let Some(before) = conversion_result.before {
+                    ranges_to_convert_next.push((input.clone(), before));
+                }
+                if let Some(behind) = conversion_result.behind {
+                    ranges_to_convert_next.push((input.clone(), behind));
+                }
+                if let Some(mapped) = conversion_result.mapped {
+                    if mapped.start < mapped.end {
+                        let mapping_source = mapping_range.source_range();
+                        steps.push(ConversionStep {
+                            input: input.clone(),
+                            part: max(part.start, mapping_source.start) .. min(part.end, mapping_source.end),
+                            mapping_range: Some(*mapping_range),
+                            output: mapped });
+                    }
+                }
+            }
+            ranges_to_convert = ranges_to_convert_next;
+            ranges_to_convert_next = Vec::new();
+        }
+
+        // now use identity transformation for the not-yet-converted values
+        for (input, part) in ranges_to_convert {
+            let output = Destination::from_u64(part.start.to_u64()) .. Destination::from_u64(part.end.to_u64());
+            steps.push(ConversionStep { input, part, mapping_range: None, output });
+        }
+
+        steps.sort_by_key(|step| (step.input.start, step.part.start));
+        steps
+    }
+
+    fn convert_range_list(&self, source_range_list:&RangeList<Source>) -> RangeList<Destination> {
+        let mut converted = Vec::new();
+        let mut first_sorted = 0;
+        for range in source_range_list.normalized().ranges {
+            // sorted ranges before this range are before all further ranges, too
+            while first_sorted < self.sorted_range_list.len() && self.sorted_range_list[first_sorted].source_range().end <= range.start {
+                first_sorted += 1;
+            }
+            let mut start = range.start;
+            for sorted_range in self.sorted_range_list[first_sorted..].iter().take_while(|sorted_range| sorted_range.source_range_start < range.end) {
+                // identity before the sorted range
+                if start < sorted_range.source_range_start {
+                    converted.push(Destination::from_u64(start.to_u64()) .. Destination::from_u64(sorted_range.source_range_start.to_u64()));
+                    start = sorted_range.source_range_start;
+                }
+                let end = min(range.end, sorted_range.source_range().end);
+                converted.push(sorted_range.convert(start) .. sorted_range.convert(end));
+                start = end;
+            }
+            if start < range.end {
+                converted.push(Destination::from_u64(start.to_u64()) .. Destination::from_u64(range.end.to_u64()));
+            }
+        }
+        RangeList::create_real_ranges(&converted).normalized()
+    }
+
+}
+
+// a part of a source range and how it is converted (None = identity)
+#[derive(Debug)]
+struct ConversionStep<Source:AlmanacType, Destination:AlmanacType> {
+    input: Range<Source>,
+    part: Range<Source>,
+    mapping_range: Option<MappingRange<Destination, Source>>,
+    output: Range<Destination>
+}
+
+// 79..93 by 52 50 48 -> 81..95
+// 74..88 part 77..88 by 45 77 23 -> 45..56
+// 81..95 unchanged
+impl<Source:AlmanacType, Destination:AlmanacType> fmt::Display for ConversionStep<Source, Destination> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.input.start.to_u64(), self.input.end.to_u64())?;
+        if self.part != self.input {
+            write!(f, " part {}..{}", self.part.start.to_u64(), self.part.end.to_u64())?;
+        }
+        match &self.mapping_range {
+            Some(mapping_range) => write!(f, " by {} {} {} -> {}..{}",
+                mapping_range.destination_range_start.to_u64(), mapping_range.source_range_start.to_u64(), mapping_range.range_length,
+                self.output.start.to_u64(), self.output.end.to_u64()),
+            None => write!(f, " unchanged")
+        }
+    }
+}
+
+#[test]
+fn test_explain() {
+    let mut light_to_temperature = SourceToDestinationMap::<Value, Value>::new();
+    light_to_temperature.add_range(Value(77),Value(45),23);
+    light_to_temperature.add_range(Value(45),Value(81),19);
+    light_to_temperature.add_range(Value(64),Value(68),13);
+    let lights = RangeList::create_real_ranges(&vec![Value(74)..Value(88), Value(30)..Value(40), Value(95)..Value(110)]);
+    let steps:Vec<String> = light_to_temperature.explain(&lights).iter().map(|step| step.to_string()).collect();
+    assert_eq!(steps, [
+        "30..40 unchanged",
+        "74..88 part 74..77 by 68 64 13 -> 78..81",
+        "74..88 part 77..88 by 45 77 23 -> 45..56",
+        "95..110 part 95..100 by 45 77 23 -> 63..68",
+        "95..110 part 100..110 unchanged",
+    ]);
+}
+
+impl<Source:AlmanacType, Destination:AlmanacType> SourceToDestinationMap<Source, Destination> {
+    // the mapping ranges as they are applied, sorted by source and without overlaps (the first mapping range wins).
+    // the gaps are filled with identity mapping ranges, so that all values up to u64::MAX are covered
+    fn segments(&self) -> Vec<MappingRange<Destination, Source>> {
+        let identity = |start:u64, end:u64| MappingRange {
+            destination_range_start: Destination::from_u64(start),
+            source_range_start: Source::from_u64(start),
+            range_length: end - start };
+        let mut segments = Vec::with_capacity(2 * self.sorted_range_list.len() + 1);
+        let mut start = 0;
+        for sorted_range in &self.sorted_range_list {
+            if start < sorted_range.source_range_start.to_u64() {
+                segments.push(identity(start, sorted_range.source_range_start.to_u64()));
+            }
+            segments.push(*sorted_range);
+            start = sorted_range.source_range().end.to_u64();
+        }
+        if start < u64::MAX {
+            segments.push(identity(start, u64::MAX));
+        }
+        segments
+    }
+
+    // add a mapping range for the composition:
+    // identity is left out and a continuation of the previous mapping range is merged into it
+    fn add_composed_range(&mut self, source_range_start: Source, destination_range_start: Destination, range_length: u64) {
+        if source_range_start.to_u64() == destination_range_start.to_u64() {
+            return;
+        }
+        // composed ranges are added in the order of their sources, so the last mapping range is the last sorted range, too
+        if let (Some(last), Some(last_sorted)) = (self.mapping_range_list.last_mut(), self.sorted_range_list.last_mut()) {
+            if last.source_range_start.to_u64() + last.range_length == source_range_start.to_u64()
+            && last.destination_range_start.to_u64() + last.range_length == destination_range_start.to_u64() {
+                last.range_length += range_length;
+                last_sorted.range_length += range_length;
+                return;
+            }
+        }
+        self.add_range(source_range_start, destination_range_start, range_length);
+    }
+
+    // first self, then next: A -> B and B -> C gives A -> C
+    // every segment of self is split where its destination crosses segments of next
+    fn compose<Next:AlmanacType>(&self, next:&SourceToDestinationMap<Destination, Next>) -> SourceToDestinationMap<Source, Next> {
+        let next_segments = next.segments();
+        let mut composed = SourceToDestinationMap::new();
+        for segment in self.segments() {
+            let image_start = segment.destination_range_start.to_u64();
+            let image_end = image_start + segment.range_length;
+            // the first segment of next which ends behind the start of the image
+            let first_next = next_segments.partition_point(|next_segment| next_segment.source_range().end.to_u64() <= image_start);
+            for next_segment in next_segments[first_next..].iter().take_while(|next_segment| next_segment.source_range_start.to_u64() < image_end) {
+                let start = max(image_start, next_segment.source_range_start.to_u64());
+                let end = min(image_end, next_segment.source_range().end.to_u64());
+                composed.add_composed_range(
+                    Source::from_u64(segment.source_range_start.to_u64() + (start - image_start)),
+                    next_segment.convert(Destination::from_u64(start)),
+                    end - start);
+            }
+        }
+        composed
+    }
+
+    // Destination -> Source with an explicit mapping range for every segment, including the identity gaps.
+    // exact if every destination is hit exactly once (like in the puzzle),
+    // otherwise the segment with the lowest destination start wins
+    fn inverse(&self) -> SourceToDestinationMap<Destination, Source> {
+        let mut segments = self.segments();
+        segments.sort_by_key(|segment| segment.destination_range_start);
+        let mut inverse = SourceToDestinationMap::new();
+        for segment in segments {
+            inverse.add_range(segment.destination_range_start, segment.source_range_start, segment.range_length);
+        }
+        inverse
+    }
+
+    // all sources which are converted into one of the destinations
+    fn preimage(&self, destinations:&RangeList<Destination>) -> RangeList<Source> {
+        let mut sources = Vec::new();
+        for segment in self.segments() {
+            let segment_destination = RangeList::create_real_ranges(&vec![segment.destination_range()]);
+            for range in segment_destination.intersection(destinations).ranges {
+                let start = segment.source_range_start.to_u64() + (range.start.to_u64() - segment.destination_range_start.to_u64());
+                sources.push(Source::from_u64(start) .. Source::from_u64(start + (range.end.to_u64() - range.start.to_u64())));
+            }
+        }
+        RangeList::create_real_ranges(&sources).normalized()
+    }
+}
+
+#[test]
+fn test_compose() {
+    // 4..6 -> 14..16, 7..9 -> 27..29
+    let mut seed_to_soil = SourceToDestinationMap::<Value, Value>::new();
+    seed_to_soil.add_range(Value(4),Value(14),2);
+    seed_to_soil.add_range(Value(7),Value(27),2);
+    seed_to_soil.add_range(Value(8),Value(99),5);
+    let segments:Vec<(u64, u64, u64)> = seed_to_soil.segments().iter()
+        .map(|segment| (segment.source_range_start.0, segment.destination_range_start.0, segment.range_length))
+        .collect();
+    assert_eq!(segments, [(0, 0, 4), (4, 14, 2), (6, 6, 1), (7, 27, 2), (9, 100, 4), (13, 13, u64::MAX - 13)]);
+
+    // 15..28 -> 115..128
+    let mut soil_to_fertilizer = SourceToDestinationMap::<Value, Value>::new();
+    soil_to_fertilizer.add_range(Value(15),Value(115),13);
+    let composed = seed_to_soil.compose(&soil_to_fertilizer);
+    let ranges:Vec<(u64, u64, u64)> = composed.mapping_range_list.iter()
+        .map(|range| (range.source_range_start.0, range.destination_range_start.0, range.range_length))
+        .collect();
+    assert_eq!(ranges, [(4, 14, 1), (5, 115, 1), (7, 127, 1), (8, 28, 1), (9, 100, 4), (15, 115, 13)]);
+    for seed in 0..40 {
+        assert_eq!(composed.convert(Value(seed)).0, soil_to_fertilizer.convert(seed_to_soil.convert(Value(seed))).0, "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_inverse() {
+    let mut seed_to_soil = SourceToDestinationMap::<Value, Value>::new();
+    seed_to_soil.add_range(Value(98),Value(50),2);
+    seed_to_soil.add_range(Value(50),Value(52),48);
+    let soil_to_seed = seed_to_soil.inverse();
+    for (soil, seed) in [(50, 98), (51, 99), (52, 50), (81, 79), (99, 97), (0, 0), (100, 100)] {
+        assert_eq!(soil_to_seed.convert(Value(soil)), Value(seed));
+    }
+
+    // 50..52 is not hit, 52..54 is hit twice
+    let mut seed_to_soil = SourceToDestinationMap::<Value, Value>::new();
+    seed_to_soil.add_range(Value(50),Value(52),2);
+    let soils = RangeList::create_real_ranges(&vec![Value(49)..Value(53)]);
+    assert_eq!(seed_to_soil.preimage(&soils).ranges, [Value(49)..Value(51), Value(52)..Value(53)]);
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+// a permutation of blocks within 0..sum(lengths)
+#[cfg(test)]
+fn bijective_map() -> impl Strategy<Value = SourceToDestinationMap<Value, Value>> {
+    prop::collection::vec(1u64..20, 1..8)
+        .prop_flat_map(|lengths| {
+            let order:Vec<usize> = (0..lengths.len()).collect();
+            (Just(lengths), Just(order).prop_shuffle())
+        })
+        .prop_map(|(lengths, order)| {
+            let mut destination_starts = vec![0; lengths.len()];
+            let mut destination_start = 0;
+            for block in order {
+                destination_starts[block] = destination_start;
+                destination_start += lengths[block];
+            }
+            let mut map = SourceToDestinationMap::new();
+            let mut source_start = 0;
+            for (length, destination_start) in lengths.iter().zip(destination_starts) {
+                map.add_range(Value(source_start), Value(destination_start), *length);
+                source_start += length;
+            }
+            map
+        })
+}
+
+#[cfg(test)]
+fn any_map() -> impl Strategy<Value = SourceToDestinationMap<Value, Value>> {
+    prop::collection::vec((0u64..100, 0u64..100, 0u64..20), 0..8)
+        .prop_map(|ranges| {
+            let mut map = SourceToDestinationMap::new();
+            for (source_start, destination_start, length) in ranges {
+                map.add_range(Value(source_start), Value(destination_start), length);
+            }
+            map
+        })
+}
+
+#[cfg(test)]
+fn any_soils() -> impl Strategy<Value = RangeList<Value>> {
+    prop::collection::vec((0u64..130, 0u64..20), 0..5)
+        .prop_map(|ranges| RangeList::create_real_ranges(&ranges.iter().map(|(start, length)| Value(*start)..Value(start + length)).collect()))
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn test_inverse_of_bijective_map(map in bijective_map(), value in 0u64..200) {
+        let inverse = map.inverse();
+        prop_assert_eq!(inverse.convert(map.convert(Value(value))), Value(value));
+        prop_assert_eq!(map.convert(inverse.convert(Value(value))), Value(value));
+    }
+
+    #[test]
+    fn test_inverse_of_bijective_range_list(map in bijective_map(), soils in any_soils()) {
+        let seeds = map.inverse().convert_range_list(&soils);
+        prop_assert_eq!(&seeds.ranges, &map.preimage(&soils).ranges);
+        prop_assert_eq!(map.convert_range_list(&seeds).ranges, soils.normalized().ranges);
+    }
+
+    #[test]
+    fn test_preimage(map in any_map(), soils in any_soils()) {
+        let seeds = map.preimage(&soils);
+        prop_assert!(seeds.is_normalized());
+        for seed in 0..150 {
+            prop_assert_eq!(seeds.contains(Value(seed)), soils.contains(map.convert(Value(seed))), "seed {}", seed);
+        }
+        prop_assert!(soils.contains_all(&map.convert_range_list(&seeds)));
+    }
+
+    #[test]
+    fn test_sorted_map_agrees(map in any_map(), seeds in any_soils()) {
+        // the first mapping range of the almanac converts the value
+        for seed in (0..150).map(Value) {
+            let first = map.mapping_range_list.iter().find(|mapping_range| mapping_range.is_source_in_range(seed));
+            prop_assert_eq!(map.convert(seed), first.map_or(seed, |mapping_range| mapping_range.convert(seed)), "{:?}", seed);
+        }
+        let converted_one_by_one = RangeList::create_single_valued_ranges(&seeds.iter().map(|seed| map.convert(seed)).collect());
+        prop_assert_eq!(map.convert_range_list(&seeds).ranges, converted_one_by_one.normalized().ranges);
+    }
+}
+
+#[test]
+fn test_sorted_map() {
+    let mut seed_to_soil = SourceToDestinationMap::<Value, Value>::new();
+    seed_to_soil.add_range(Value(98),Value(50),2);
+    seed_to_soil.add_range(Value(50),Value(52),48);
+    for (seed, soil) in [(0, 0), (49, 49), (50, 52), (97, 99), (98, 50), (99, 51), (100, 100), (u64::MAX, u64::MAX)] {
+        assert_eq!(seed_to_soil.convert(Value(seed)), Value(soil));
+    }
+    let seeds = RangeList::create_real_ranges(&vec![Value(95)..Value(105), Value(40)..Value(55), Value(u64::MAX - 1)..Value(u64::MAX)]);
+    assert_eq!(seed_to_soil.convert_range_list(&seeds).ranges, [Value(40)..Value(57), Value(97)..Value(105), Value(u64::MAX - 1)..Value(u64::MAX)]);
+
+    // overlapping mapping ranges: the first one wins, the later ones fill the gaps
+    seed_to_soil.add_range(Value(90),Value(0),20);
+    seed_to_soil.add_range(Value(0),Value(1000),0);
+    let sorted:Vec<(u64, u64, u64)> = seed_to_soil.sorted_range_list.iter()
+        .map(|sorted_range| (sorted_range.source_range_start.0, sorted_range.destination_range_start.0, sorted_range.range_length))
+        .collect();
+    assert_eq!(sorted, [(50, 52, 48), (98, 50, 2), (100, 10, 10)]);
+    assert_eq!(seed_to_soil.mapping_range_list.len(), 4);
+    for (seed, soil) in [(90, 92), (99, 51), (100, 10), (109, 19), (110, 110)] {
+        assert_eq!(seed_to_soil.convert(Value(seed)), Value(soil));
+    }
+}
+
+#[test]
+fn test_convert_range_list() {
+    //  4..6 -> 14..16, 7..9 -> 27..29
+    let mappings = {
+        let mut mappings = SourceToDestinationMap::<Value, Value>::new();
+        mappings.add_range(Value(4),Value(14),2);
+        mappings.add_range(Value(7),Value(27),2);
+        mappings
+    };
+
+    let seeds = RangeList::create_real_ranges(&vec![Value(0)..Value(10)]);
+    let converted = mappings.convert_range_list(&seeds);
+
+    assert_eq!(converted.ranges,
+        vec![Value(0)..Value(4),
+             Value(6)..Value(7),
+             Value(9)..Value(10),
+             Value(14)..Value(16),
+             Value(27)..Value(29)]);
+
+    // 4..6 is mapped next to the identity 6..7, the result is merged
+    let mut mappings = SourceToDestinationMap::<Value, Value>::new();
+    mappings.add_range(Value(4),Value(5),2);
+    let converted = mappings.convert_range_list(&seeds);
+    assert_eq!(converted.ranges, vec![Value(0)..Value(4), Value(5)..Value(10)]);
+    assert_eq!(converted.len(), 9);
+
+}
+
+use pest::iterators::Pair;
+
+fn build_source_destination_map<Source:AlmanacType, Destination:AlmanacType>
+    (mapping_rule:Pair<'_, Rule>) -> SourceToDestinationMap<Source, Destination> {
+        let mut sd_map = SourceToDestinationMap::new();
+        for list_of_triples in mapping_rule.into_inner() {
+            match list_of_triples.as_rule() {
+                Rule::list_of_triples => {
+                    let mut number_iter = list_of_triples.into_inner();
+                    while let Some(destination_rule) = number_iter.next() {
+                        let destination_value = destination_rule.as_str().parse::<u64>().unwrap();
+
+                        let source_rule = number_iter.next().unwrap();
+                        let source_value = source_rule.as_str().parse::<u64>().unwrap();
+
+                        let range_rule = number_iter.next().unwrap();
+                        let range_value = range_rule.as_str().parse::<u64>().unwrap();
+
+                        sd_map.add_range(Source::from_u64(source_value), Destination::from_u64(destination_value), range_value);
+                    }
+                }
+                Rule::category => (),
+                _ => { println!("Unexpected {}", list_of_triples); }
+            }
+        }
+        sd_map
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BuildAlmanacMode {
+    Part1,
+    Part2
+}
+
+// 79 14 55 13 = [79, 14, 55, 13]
+// validate_almanac reports the seeds which do not fit into u64
+fn build_seeds1<T:AlmanacType>(seeds_rule:Pair<'_, Rule>) -> RangeList<T> {
+    let mut seeds = Vec::new();
+    for number in seeds_rule.into_inner() {
+        match number.as_rule() {
+            Rule::number => {
+                let number_value = number.as_str().parse::<u64>().unwrap();
+                seeds.push(T::from_u64(number_value));
+            }
+            _ => { println!("Unexpected {}", number); }
+        }
+    }
+    RangeList::create_single_valued_ranges(&seeds)
+}
+
+// 79 14 55 13 = [79..79+14, 55.. 55+13]
+// validate_almanac reports the ranges which do not fit into u64 and a seed without range length
+fn build_seeds2<T:AlmanacType>(seeds_rule:Pair<'_, Rule>) -> RangeList<T> {
+    let mut ranges = Vec::new();
+    let mut number_iter = seeds_rule.into_inner();
+    while let Some(seed_start_number_rule) = number_iter.next() {
+        let seed_start_number_value = seed_start_number_rule.as_str().parse::<u64>().unwrap();
+
+        let range_rule = number_iter.next().unwrap();
+        let range_value = range_rule.as_str().parse::<u64>().unwrap();
+        //println!("       Seed range: {}..{}",seed_start_number_value, seed_start_number_value + range_value);
+        ranges.push(T::from_u64(seed_start_number_value)..T::from_u64(seed_start_number_value + range_value));
+    }
+    RangeList::create_real_ranges(&ranges)
+}
+
+// "soil-to-fertilizer map:"
+struct CategoryMap {
+    source: String,
+    destination: String,
+    map: SourceToDestinationMap<Value, Value>
+}
+
+// an almanac with the categories and maps as they are in the input
+struct CategoryAlmanac {
+    seeds: RangeList<Value>,
+    maps: Vec<CategoryMap>
+}
+
+const SEED_CATEGORY:&str = "seed";
+
+// the name of a map and how it converts the values
+type StageExplanation = (String, Vec<ConversionStep<Value, Value>>);
+
+impl CategoryAlmanac {
+    // the maps on the shortest way between two categories, true if the map is used forward
+    fn path(&self, from:&str, to:&str) -> Result<Vec<(&CategoryMap, bool)>, String> {
+        for category in [from, to] {
+            if category != SEED_CATEGORY && !self.maps.iter().any(|map| map.source == category || map.destination == category) {
+                return Err(format!("unknown category '{}'", category));
+            }
+        }
+        // category -> the map which reached it and the category it came from
+        let mut reached_by:HashMap<&str, Option<(&CategoryMap, bool, &str)>> = HashMap::from([(from, None)]);
+        let mut todo = VecDeque::from([from]);
+        while let Some(category) = todo.pop_front() {
+            if category == to {
+                let mut path = Vec::new();
+                let mut current = to;
+                while let Some(Some((map, forward, previous))) = reached_by.get(current) {
+                    path.push((*map, *forward));
+                    current = previous;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            for map in &self.maps {
+                let (next, forward) =
+                    if map.source == category { (map.destination.as_str(), true) }
+                    else if map.destination == category { (map.source.as_str(), false) }
+                    else { continue };
+                if !reached_by.contains_key(next) {
+                    reached_by.insert(next, Some((map, forward, category)));
+                    todo.push_back(next);
+                }
+            }
+        }
+        Err(format!("no conversion from '{}' to '{}'", from, to))
+    }
+
+    // all maps between two categories composed into one, maps used backwards are inverted
+    fn converter(&self, from:&str, to:&str) -> Result<SourceToDestinationMap<Value, Value>, String> {
+        let mut converter = SourceToDestinationMap::new();
+        for (category_map, forward) in self.path(from, to)? {
+            converter = if forward { converter.compose(&category_map.map) } else { converter.compose(&category_map.map.inverse()) };
+        }
+        Ok(converter)
+    }
+
+    // the conversion steps of every map on the way from the seeds to a category
+    fn explain(&self, seeds:&RangeList<Value>, to:&str) -> Result<Vec<StageExplanation>, String> {
+        let mut explanation = Vec::new();
+        let mut values = seeds.clone();
+        for (category_map, forward) in self.path(SEED_CATEGORY, to)? {
+            let (name, map) =
+                if forward { (format!("{}-to-{}", category_map.source, category_map.destination), None) }
+                else { (format!("{}-to-{} (inverted)", category_map.destination, category_map.source), Some(category_map.map.inverse())) };
+            let map = map.as_ref().unwrap_or(&category_map.map);
+            let steps = map.explain(&values);
+            values = map.convert_range_list(&values);
+            explanation.push((name, steps));
+        }
+        Ok(explanation)
+    }
+
+    fn lowest_seed_conversion(&self, to:&str) -> Result<Value, String> {
+        let values = self.converter(SEED_CATEGORY, to)?.convert_range_list(&self.seeds);
+        values.min().ok_or_else(|| "no seeds".to_string())
+    }
+
+    // all seeds which end in one of the locations, not only the seeds of the almanac.
+    // converter("location", "seed") is no replacement: its inverted maps keep one seed per location
+    pub fn location_to_seed(&self, locations:&RangeList<Value>) -> Result<RangeList<Value>, String> {
+        Ok(self.converter(SEED_CATEGORY, "location")?.preimage(locations))
+    }
+
+    // search the values upward and map them back to the seeds
+    fn lowest_seed_conversion_by_inverse(&self, to:&str) -> Result<Value, String> {
+        let converter = self.converter(SEED_CATEGORY, to)?;
+        for segment in converter.inverse().segments() {
+            let values = RangeList::create_real_ranges(&vec![segment.source_range()]);
+            let seeds = converter.preimage(&values).intersection(&self.seeds);
+            if !seeds.ranges.is_empty() {
+                return converter.convert_range_list(&seeds).min().ok_or_else(|| "no seeds".to_string());
+            }
+        }
+        Err("no seeds".to_string())
+    }
+}
+
+fn build_category_map(map_rule:Pair<'_, Rule>) -> CategoryMap {
+    let mut categories = map_rule.clone().into_inner();
+    let source = categories.next().unwrap().as_str().to_string();
+    let destination = categories.next().unwrap().as_str().to_string();
+    CategoryMap { source, destination, map: build_source_destination_map(map_rule) }
+}
+
+fn build_category_almanac(file_rule:Pair<'_, Rule>, mode: BuildAlmanacMode) -> CategoryAlmanac {
+    let mut almanac = CategoryAlmanac { seeds: RangeList::new(), maps: Vec::new() };
+    for almanac_entry in file_rule.into_inner() {
+        match almanac_entry.as_rule() {
+            Rule::seeds => {
+                almanac.seeds = match mode {
+                    BuildAlmanacMode::Part1 => build_seeds1(almanac_entry),
+                    BuildAlmanacMode::Part2 => build_seeds2(almanac_entry)
+                };
+            },
+            Rule::category_map => {
+                almanac.maps.push(build_category_map(almanac_entry));
+            },
+            Rule::EOI => (),
+            _ => unreachable!(),
+        }
+    }
+    almanac
+}
+
+fn parse_category_almanac(input:&str, mode: BuildAlmanacMode) -> Result<CategoryAlmanac, String> {
+    let mut parsed = Day5Parser::parse(Rule::category_file, input).map_err(|error| error.to_string())?;
+    Ok(build_category_almanac(parsed.next().unwrap(), mode))
+}
+
+// "79" or "79..93"
+fn parse_value_range(text:&str, category:&str) -> Result<RangeList<Value>, String> {
+    let parse = |number:&str| number.trim().parse::<u64>().map_err(|_| format!("'{}' is not a {} or a range of {}s like 79..93", text, category, category));
+    let range = match text.split_once("..") {
+        Some((start, end)) => Value(parse(start)?) .. Value(parse(end)?),
+        None => { let seed = parse(text)?; Value(seed) .. Value(seed + 1) }
+    };
+    Ok(RangeList::create_real_ranges(&vec![range]))
+}
+
+// "79..93, 55..68"
+fn format_ranges(values:&RangeList<Value>) -> String {
+    if values.ranges.is_empty() {
+        return "none".to_string();
+    }
+    values.ranges.iter().map(|range| format!("{}..{}", range.start.0, range.end.0)).collect::<Vec<_>>().join(", ")
+}
+
+fn print_explanation(almanac:&CategoryAlmanac, seeds:&RangeList<Value>, to:&str) -> Result<(), String> {
+    for (map, steps) in almanac.explain(seeds, to)? {
+        println!("       {}:", map);
+        for step in steps {
+            println!("           {}", step);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_category_explain() {
+    let almanac = parse_category_almanac(&EXAMPLE_INPUT.join("\n"), BuildAlmanacMode::Part1).unwrap();
+    let explanation = almanac.explain(&parse_value_range("82", "seed").unwrap(), "location").unwrap();
+    let lines:Vec<String> = explanation.iter().map(|(map, steps)| format!("{}: {}", map, steps.iter().map(|step| step.to_string()).collect::<Vec<_>>().join(", "))).collect();
+    assert_eq!(lines, [
+        "seed-to-soil: 82..83 by 52 50 48 -> 84..85",
+        "soil-to-fertilizer: 84..85 unchanged",
+        "fertilizer-to-water: 84..85 unchanged",
+        "water-to-light: 84..85 by 18 25 70 -> 77..78",
+        "light-to-temperature: 77..78 by 45 77 23 -> 45..46",
+        "temperature-to-humidity: 45..46 by 1 0 69 -> 46..47",
+        "humidity-to-location: 46..47 unchanged",
+    ]);
+
+    let explanation = almanac.explain(&parse_value_range("95..100", "seed").unwrap(), "fertilizer").unwrap();
+    assert_eq!(explanation.len(), 2);
+    assert_eq!(explanation[0].1.iter().map(|step| step.to_string()).collect::<Vec<_>>(), [
+        "95..100 part 95..98 by 52 50 48 -> 97..100", "95..100 part 98..100 by 50 98 2 -> 50..52"
+    ]);
+    // the soils are normalized before the next map
+    assert_eq!(explanation[1].1.iter().map(|step| step.to_string()).collect::<Vec<_>>(), [
+        "50..52 by 0 15 37 -> 35..37", "97..100 unchanged"
+    ]);
+    assert_eq!(almanac.explain(&parse_value_range("1", "seed").unwrap(), "soil").unwrap()[0].0, "seed-to-soil");
+    assert_eq!(almanac.explain(&parse_value_range("1", "seed").unwrap(), "colour").unwrap_err(), "unknown category 'colour'");
+    assert!(parse_value_range("79..", "seed").is_err());
+}
+
+#[test]
+fn test_category_almanac() {
+    let input = EXAMPLE_INPUT.join("\n");
+    let almanac = parse_category_almanac(&input, BuildAlmanacMode::Part1).unwrap();
+    assert_eq!(almanac.maps.len(), 7);
+    assert_eq!(almanac.lowest_seed_conversion("location"), Ok(Value(35)));
+    // seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82
+    let seed_79 = RangeList::create_single_valued_ranges(&vec![Value(79)]);
+    for (category, value) in [("seed", 79), ("soil", 81), ("light", 74), ("humidity", 78), ("location", 82)] {
+        assert_eq!(almanac.converter("seed", category).unwrap().convert_range_list(&seed_79).ranges, [Value(value)..Value(value+1)]);
+    }
+    // backwards
+    assert_eq!(almanac.converter("location", "seed").unwrap().convert(Value(82)), Value(79));
+    assert_eq!(almanac.converter("humidity", "water").unwrap().convert(Value(78)), Value(81));
+    assert_eq!(almanac.lowest_seed_conversion("colour").unwrap_err(), "unknown category 'colour'");
+
+    assert_eq!(almanac.lowest_seed_conversion_by_inverse("location"), Ok(Value(35)));
+
+    let almanac = parse_category_almanac(&input, BuildAlmanacMode::Part2).unwrap();
+    assert_eq!(almanac.lowest_seed_conversion("location"), Ok(Value(46)));
+    assert_eq!(almanac.lowest_seed_conversion_by_inverse("location"), Ok(Value(46)));
+    assert_eq!(almanac.lowest_seed_conversion_by_inverse("humidity"), almanac.lowest_seed_conversion("humidity"));
+
+    // no seeds in part 2
+    let almanac = parse_category_almanac(&input.replacen("seeds: 79 14 55 13", "seeds: 79 0", 1), BuildAlmanacMode::Part2).unwrap();
+    assert_eq!(almanac.lowest_seed_conversion("location").unwrap_err(), "no seeds");
+    assert_eq!(almanac.lowest_seed_conversion_by_inverse("location").unwrap_err(), "no seeds");
+
+    // reordered, an extra stage and a branch
+    let input = [
+        "seeds: 1 5",
+        "",
+        "seed-to-colour map:",
+        "10 0 10",
+        "",
+        "soil-to-location map:",
+        "100 20 5",
+        "20 100 5",
+        "",
+        "colour-to-soil map:",
+        "20 10 10",
+        "",
+        "colour-to-shape map:",
+        "7 11 1",
+        "",
+        "planet-to-moon map:",
+        "0 0 1",
+    ].join("\n");
+    let almanac = parse_category_almanac(&input, BuildAlmanacMode::Part1).unwrap();
+    // seed 1 -> colour 11 -> soil 21 -> location 101, seed 5 -> colour 15 -> soil 25 -> location 25
+    assert_eq!(almanac.lowest_seed_conversion("location"), Ok(Value(25)));
+    assert_eq!(almanac.lowest_seed_conversion("shape"), Ok(Value(7)));
+    // location -> soil -> colour -> shape
+    assert_eq!(almanac.converter("location", "shape").unwrap().convert(Value(101)), Value(7));
+    assert_eq!(almanac.lowest_seed_conversion("moon").unwrap_err(), "no conversion from 'seed' to 'moon'");
+}
+
+#[test]
+fn test_location_to_seed() {
+    let almanac = parse_category_almanac(&EXAMPLE_INPUT.join("\n"), BuildAlmanacMode::Part1).unwrap();
+    let converter = almanac.converter("seed", "location").unwrap();
+    let locations = parse_value_range("40..50", "location").unwrap();
+    let seeds = almanac.location_to_seed(&locations).unwrap();
+    let expected:Vec<u64> = (0..200).filter(|seed| locations.contains(converter.convert(Value(*seed)))).collect();
+    assert_eq!(seeds.to_vec().iter().map(|seed| seed.0).collect::<Vec<_>>(), expected);
+    assert!(seeds.contains(Value(82)));
+
+    // seeds 2, 12 and 22 all end in location 12, the inverted map only finds one of them
+    let input = "seeds: 2 22\n\nseed-to-location map:\n10 0 5\n10 20 5";
+    let almanac = parse_category_almanac(input, BuildAlmanacMode::Part1).unwrap();
+    let location_12 = parse_value_range("12", "location").unwrap();
+    assert_eq!(almanac.location_to_seed(&location_12).unwrap().ranges, [Value(2)..Value(3), Value(12)..Value(13), Value(22)..Value(23)]);
+    assert_eq!(almanac.converter("location", "seed").unwrap().convert_range_list(&location_12).ranges.len(), 1);
+    assert_eq!(format_ranges(&almanac.location_to_seed(&location_12).unwrap().intersection(&almanac.seeds)), "2..3, 22..23");
+
+    let almanac = parse_category_almanac("seeds: 1\n\nseed-to-soil map:\n0 0 1", BuildAlmanacMode::Part1).unwrap();
+    assert_eq!(almanac.location_to_seed(&location_12).unwrap_err(), "unknown category 'location'");
+    assert_eq!(format_ranges(&RangeList::new()), "none");
+}
+
+#[derive(Debug, PartialEq)]
+enum Anomaly {
+    // the source ranges share values, the values are converted by the first one only
+    SourceOverlap { other_line: usize },
+    // the destination ranges share values, two sources are converted into the same destination
+    DestinationCollision { other_line: usize },
+    ZeroLength,
+    // start + range length does not fit into u64
+    SourceOverflow,
+    DestinationOverflow,
+    // a seed of part 1 is a range of length 1
+    SeedRangeOverflow { part: u8, start: u64, length: u64 },
+    // part 2 reads the seeds in pairs
+    MissingRangeLength { start: u64 }
+}
+
+impl Anomaly {
+    // the almanac cannot be built
+    fn is_fatal(&self) -> bool {
+        matches!(self, Anomaly::SourceOverflow | Anomaly::DestinationOverflow | Anomaly::SeedRangeOverflow{..} | Anomaly::MissingRangeLength{..})
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Finding {
+    line: usize,
+    // "seeds" or "seed-to-soil map"
+    section: String,
+    anomaly: Anomaly
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, {}: ", self.line, self.section)?;
+        match &self.anomaly {
+            Anomaly::SourceOverlap{other_line}        => write!(f, "source range overlaps with line {}", other_line),
+            Anomaly::DestinationCollision{other_line} => write!(f, "destination range collides with line {}", other_line),
+            Anomaly::ZeroLength                       => write!(f, "range length is 0"),
+            Anomaly::SourceOverflow                   => write!(f, "source range ends behind {}", u64::MAX),
+            Anomaly::DestinationOverflow              => write!(f, "destination range ends behind {}", u64::MAX),
+            Anomaly::SeedRangeOverflow{part, start, length} => write!(f, "part {}: seed range {} + {} ends behind {}", part, start, length, u64::MAX),
+            Anomaly::MissingRangeLength{start}        => write!(f, "part 2: seed {} has no range length", start)
+        }
+    }
+}
+
+// "52 50 48" with its line in the input
+struct MappingLine {
+    line: usize,
+    destination_range_start: u64,
+    source_range_start: u64,
+    range_length: u64
+}
+
+fn build_mapping_lines(map_rule:Pair<'_, Rule>) -> Result<Vec<MappingLine>, String> {
+    let mut mapping_lines = Vec::new();
+    let list_of_triples = map_rule.into_inner().find(|inner| inner.as_rule() == Rule::list_of_triples).unwrap();
+    let mut number_iter = list_of_triples.into_inner();
+    while let Some(destination_rule) = number_iter.next() {
+        let line = destination_rule.line_col().0;
+        let parse = |number:Pair<'_, Rule>| number.as_str().parse::<u64>()
+            .map_err(|_| format!("line {}: number {} is too large", line, number.as_str()));
+        mapping_lines.push(MappingLine {
+            line,
+            destination_range_start: parse(destination_rule)?,
+            source_range_start: parse(number_iter.next().unwrap())?,
+            range_length: parse(number_iter.next().unwrap())? });
+    }
+    Ok(mapping_lines)
+}
+
+fn validate_map(map:&str, mapping_lines:&[MappingLine]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut report = |line, anomaly| findings.push(Finding{ line, section:format!("{} map", map), anomaly });
+    // the ranges which can be compared with others
+    let mut ranges = Vec::new();
+    for mapping_line in mapping_lines {
+        let source_end = mapping_line.source_range_start.checked_add(mapping_line.range_length);
+        let destination_end = mapping_line.destination_range_start.checked_add(mapping_line.range_length);
+        if mapping_line.range_length == 0 {
+            report(mapping_line.line, Anomaly::ZeroLength);
+        }
+        if source_end.is_none() {
+            report(mapping_line.line, Anomaly::SourceOverflow);
+        }
+        if destination_end.is_none() {
+            report(mapping_line.line, Anomaly::DestinationOverflow);
+        }
+        if let (Some(source_end), Some(destination_end)) = (source_end, destination_end) {
+            if mapping_line.range_length > 0 {
+                ranges.push((mapping_line.line,
+                    mapping_line.source_range_start..source_end,
+                    mapping_line.destination_range_start..destination_end));
+            }
+        }
+    }
+    let overlap = |left:&Range<u64>, right:&Range<u64>| left.start < right.end && right.start < left.end;
+    for (index, (line, source, destination)) in ranges.iter().enumerate() {
+        for (other_line, other_source, other_destination) in &ranges[..index] {
+            if overlap(source, other_source) {
+                report(*line, Anomaly::SourceOverlap{ other_line:*other_line });
+            }
+            if overlap(destination, other_destination) {
+                report(*line, Anomaly::DestinationCollision{ other_line:*other_line });
+            }
+        }
+    }
+    findings
+}
+
+// the seeds as ranges of part 1 (every seed alone) and part 2 (pairs of start and range length)
+fn validate_seeds(seeds_rule:Pair<'_, Rule>) -> Result<Vec<Finding>, String> {
+    let line = seeds_rule.line_col().0;
+    let seeds = seeds_rule.into_inner()
+        .map(|number| number.as_str().parse::<u64>().map_err(|_| format!("line {}: number {} is too large", line, number.as_str())))
+        .collect::<Result<Vec<u64>, String>>()?;
+    let mut findings = Vec::new();
+    let mut report = |anomaly| findings.push(Finding{ line, section:"seeds".to_string(), anomaly });
+    for seed in &seeds {
+        if seed.checked_add(1).is_none() {
+            report(Anomaly::SeedRangeOverflow{ part:1, start:*seed, length:1 });
+        }
+    }
+    for pair in seeds.chunks(2) {
+        match pair {
+            [start, length] => if start.checked_add(*length).is_none() {
+                report(Anomaly::SeedRangeOverflow{ part:2, start:*start, length:*length });
+            },
+            [start] => report(Anomaly::MissingRangeLength{ start:*start }),
+            _ => unreachable!()
+        }
+    }
+    Ok(findings)
+}
+
+fn validate_almanac(input:&str) -> Result<Vec<Finding>, String> {
+    let mut parsed = Day5Parser::parse(Rule::category_file, input).map_err(|error| error.to_string())?;
+    let mut findings = Vec::new();
+    for almanac_entry in parsed.next().unwrap().into_inner() {
+        match almanac_entry.as_rule() {
+            Rule::seeds => findings.extend(validate_seeds(almanac_entry)?),
+            Rule::category_map => {
+                let map = almanac_entry.as_str().split(' ').next().unwrap().to_string();
+                findings.extend(validate_map(&map, &build_mapping_lines(almanac_entry)?));
+            }
+            _ => ()
+        }
+    }
+    Ok(findings)
+}
+
+#[test]
+fn test_validate_almanac() {
+    assert_eq!(validate_almanac(&EXAMPLE_INPUT.join("\n")), Ok(vec![]));
+
+    let input = [
+        "seeds: 1 5",
+        "",
+        "seed-to-soil map:",
+        "10 0 10",
+        "30 5 10",
+        "15 40 0",
+        "12 18446744073709551610 5",
+        "0 18446744073709551610 7",
+        "",
+        "soil-to-location map:",
+        "18446744073709551614 0 1",
+        "18446744073709551615 1 2",
+    ].join("\n");
+    let findings = validate_almanac(&input).unwrap();
+    let lines:Vec<String> = findings.iter().map(|finding| finding.to_string()).collect();
+    assert_eq!(lines, [
+        "line 6, seed-to-soil map: range length is 0",
+        "line 8, seed-to-soil map: source range ends behind 18446744073709551615",
+        "line 5, seed-to-soil map: source range overlaps with line 4",
+        "line 7, seed-to-soil map: destination range collides with line 4",
+        "line 12, soil-to-location map: destination range ends behind 18446744073709551615",
+    ]);
+
+    assert_eq!(validate_almanac("seeds: 1\n\nseed-to-soil map:\n1 18446744073709551616 1"),
+        Err("line 4: number 18446744073709551616 is too large".to_string()));
+
+    assert_eq!(validate_almanac("seeds: 1 18446744073709551616\n\nseed-to-soil map:\n1 1 1"),
+        Err("line 1: number 18446744073709551616 is too large".to_string()));
+    let findings = validate_almanac("seeds: 18446744073709551615 2 18446744073709551610 5 7\n\nseed-to-soil map:\n1 1 1").unwrap();
+    let lines:Vec<String> = findings.iter().map(|finding| finding.to_string()).collect();
+    assert_eq!(lines, [
+        "line 1, seeds: part 1: seed range 18446744073709551615 + 1 ends behind 18446744073709551615",
+        "line 1, seeds: part 2: seed range 18446744073709551615 + 2 ends behind 18446744073709551615",
+        "line 1, seeds: part 2: seed 7 has no range length",
+    ]);
+    assert!(findings.iter().all(|finding| finding.anomaly.is_fatal()));
+    // 18446744073709551610 + 5 just fits
+    assert_eq!(validate_almanac("seeds: 18446744073709551610 5\n\nseed-to-soil map:\n1 1 1"), Ok(vec![]));
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT:[&str;33] = [
+        "seeds: 79 14 55 13",
+        "",
+        "seed-to-soil map:",
+        "50 98 2",
+        "52 50 48",
+        "",
+        "soil-to-fertilizer map:",
+        "0 15 37",
+        "37 52 2",
+        "39 0 15",
+        "",
+        "fertilizer-to-water map:",
+        "49 53 8",
+        "0 11 42",
+        "42 0 7",
+        "57 7 4",
+        "",
+        "water-to-light map:",
+        "88 18 7",
+        "18 25 70",
+        "",
+        "light-to-temperature map:",
+        "45 77 23",
+        "81 45 19",
+        "68 64 13",
+        "",
+        "temperature-to-humidity map:",
+        "0 69 1",
+        "1 0 69",
+        "",
+        "humidity-to-location map:",
+        "60 56 37",
+        "56 93 4"
+];
+
+#[test]
+fn test_example1() {
+    let almanac = parse_category_almanac(&EXAMPLE_INPUT.join("\n"), BuildAlmanacMode::Part1).unwrap();
+
+    assert_eq!(&almanac.seeds.ranges, &vec![Value(79)..Value(80), Value(14)..Value(15), Value(55)..Value(56), Value(13)..Value(14)]);
+    let mapping_ranges:Vec<usize> = almanac.maps.iter().map(|category_map| category_map.map.mapping_range_list.len()).collect();
+    assert_eq!(mapping_ranges, [2, 3, 4, 2, 3, 2, 2]);
+
+    // the maps of the example are in the order of the stages
+    let stages = [
+        ("soil",        [13, 14, 57, 81]),
+        ("fertilizer",  [52, 53, 57, 81]),
+        ("water",       [41, 49, 53, 81]),
+        ("light",       [34, 42, 46, 74]),
+        ("temperature", [34, 42, 78, 82]),
+        ("humidity",    [35, 43, 78, 82]),
+        ("location",    [35, 43, 82, 86])];
+    let mut values = almanac.seeds.clone();
+    for (category_map, (category, expected)) in almanac.maps.iter().zip(stages) {
+        assert_eq!(category_map.destination, category);
+        values = category_map.map.convert_range_list(&values);
+        assert_eq!(values.to_sorted_vec(), expected.map(Value));
+        assert_eq!(almanac.converter("seed", category).unwrap().convert_range_list(&almanac.seeds).ranges, values.ranges);
+    }
+
+    let lowest_location = values.min().unwrap();
+    assert_eq!(lowest_location, Value(35));
+
+}
+
+#[test]
+fn test_example2() {
+    let almanac = parse_category_almanac(&EXAMPLE_INPUT.join("\n"), BuildAlmanacMode::Part2).unwrap();
+
+    let seed_exp:Vec<Value> = (79..79+14).chain(55..55+13).map(Value).collect();
+    assert_eq!(almanac.seeds.to_vec(), seed_exp);
+
+    // check fourth value
+    let seed = almanac.seeds.to_vec()[3];
+    assert_eq!(seed, Value(82));
+    let mut value = seed;
+    for (category_map, expected) in almanac.maps.iter().zip([84, 84, 84, 77, 45, 46, 46]) {
+        value = category_map.map.convert(value);
+        assert_eq!(value, Value(expected), "{}", category_map.destination);
+    }
+
+    let mut stages = vec![almanac.seeds.clone()];
+    for category_map in &almanac.maps {
+        stages.push(category_map.map.convert_range_list(stages.last().unwrap()));
+    }
+    for stage in &stages[1..] {
+        assert!(stage.is_normalized());
+    }
+    let locations = stages.last().unwrap();
+    assert_eq!(locations.len(), almanac.seeds.len());
+    let lowest_location = locations.min().unwrap();
+    assert_eq!(lowest_location, Value(46));
+
+    let seed_to_location = almanac.converter("seed", "location").unwrap();
+    assert_eq!(seed_to_location.convert_range_list(&almanac.seeds).ranges, locations.ranges);
+    for seed in (0..120).map(Value) {
+        let chained = almanac.maps.iter().fold(seed, |value, category_map| category_map.map.convert(value));
+        assert_eq!(seed_to_location.convert(seed), chained, "{:?}", seed);
+    }
+
+    assert_eq!(almanac.location_to_seed(&RangeList::create_single_valued_ranges(&vec![Value(46)])).unwrap().ranges, [Value(82)..Value(83)]);
+    assert!(almanac.location_to_seed(locations).unwrap().contains_all(&almanac.seeds));
+}
+
+use std::time::Instant;
+
+// a permutation of blocks with gaps, like the maps of the puzzle
+fn generate_map(mapping_ranges:usize, seed:u64) -> SourceToDestinationMap<Value, Value> {
+    let mut state = seed.max(1);
+    let mut next = |bound:u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % bound
+    };
+    let mut blocks = Vec::with_capacity(mapping_ranges);
+    let mut start = 0;
+    for _ in 0..mapping_ranges {
+        start += next(1000);
+        let length = 1 + next(100_000);
+        blocks.push((start, length));
+        start += length;
+    }
+    let mut order:Vec<usize> = (0..mapping_ranges).collect();
+    for index in (1..order.len()).rev() {
+        order.swap(index, next(index as u64 + 1) as usize);
+    }
+    // the blocks are placed in the destination in shuffled order and added in that order, unsorted like in the input
+    let mut map = SourceToDestinationMap::new();
+    let mut destination_start = 0;
+    for block in order {
+        let (source_start, length) = blocks[block];
+        destination_start += next(1000);
+        map.add_range(Value(source_start), Value(destination_start), length);
+        destination_start += length;
+    }
+    map
+}
+
+#[test]
+fn test_generate_map() {
+    let map = generate_map(1000, 5);
+    assert_eq!(map.mapping_range_list.len(), 1000);
+    // the blocks do not overlap, every one is found in the sorted ranges
+    assert_eq!(map.sorted_range_list.len(), 1000);
+    for mapping_range in &map.mapping_range_list {
+        let source = mapping_range.source_range();
+        assert_eq!(map.convert(source.start), mapping_range.destination_range_start);
+        assert_eq!(map.convert(Value(source.end.0 - 1)), Value(mapping_range.destination_range().end.0 - 1));
+        assert_eq!(map.convert_range_list(&RangeList::create_real_ranges(&vec![source])).ranges, [mapping_range.destination_range()]);
+    }
+}
+
+fn benchmark(mapping_ranges:usize) {
+    let map = generate_map(mapping_ranges, 2023);
+    let end = map.mapping_range_list.iter().map(|mapping_range| mapping_range.source_range().end.0).max().unwrap_or(1);
+    let values:Vec<Value> = (0..100_000).map(|index| Value(index * (end / 100_000 + 1))).collect();
+    let ranges = RangeList::create_real_ranges(&values.iter().step_by(10).map(|value| *value .. Value(value.0 + 50_000)).collect());
+    println!("Day 5 benchmark: {} mapping ranges, {} values and {} ranges", mapping_ranges, values.len(), ranges.ranges.len());
+
+    // the baseline: every mapping range is tried in the order of the almanac
+    let convert_linearly = |value:Value| map.mapping_range_list.iter()
+        .find(|mapping_range| mapping_range.is_source_in_range(value))
+        .map_or(value, |mapping_range| mapping_range.convert(value));
+    let convert_range_list_linearly = |range_list:&RangeList<Value>| {
+        let mut ranges_to_convert = range_list.ranges.clone();
+        let mut ranges_converted = Vec::new();
+        for mapping_range in &map.mapping_range_list {
+            let mut ranges_to_convert_next = Vec::new();
+            for range in &ranges_to_convert {
+                let conversion_result = mapping_range.convert_range(range);
+                ranges_to_convert_next.extend(conversion_result.before);
+                ranges_to_convert_next.extend(conversion_result.behind);
+                ranges_converted.extend(conversion_result.mapped);
+            }
+            ranges_to_convert = ranges_to_convert_next;
+        }
+        // identity for the not converted values
+        ranges_converted.extend(ranges_to_convert);
+        RangeList::create_real_ranges(&ranges_converted).normalized()
+    };
+
+    let start = Instant::now();
+    let linear_values:Vec<Value> = values.iter().map(|value| convert_linearly(*value)).collect();
+    let linear_ranges = convert_range_list_linearly(&ranges);
+    let linear_duration = start.elapsed();
+
+    let start = Instant::now();
+    let sorted_values:Vec<Value> = values.iter().map(|value| map.convert(*value)).collect();
+    let sorted_ranges = map.convert_range_list(&ranges);
+    let sorted_duration = start.elapsed();
+
+    println!("       linear: {} ranges in {:?}", linear_ranges.ranges.len(), linear_duration);
+    println!("       sorted: {} ranges in {:?}", sorted_ranges.ranges.len(), sorted_duration);
+    assert!(linear_values == sorted_values && linear_ranges.ranges == sorted_ranges.ranges, "Linear and sorted disagree");
+}
+
+use common::runner::{Answers, Options, Solution};
+use std::fmt;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+pub fn part1and2(options:&Options) -> Solution {
+    if options.is_set("benchmark") {
+        benchmark(options.get("ranges").map_or(5000, |ranges| ranges.parse().expect("ranges needs a number")));
+        return Ok(Answers::default());
+    }
+
+    let by_inverse = match options.get("strategy") {
+        None | Some("forward") => false,
+        Some("inverse") => true,
+        Some(other) => return Err(format!("unknown strategy '{}', use forward or inverse", other))
+    };
+
+    let concat_input = crate::read_input_lines(5).join("\n");
+    match validate_almanac(&concat_input) {
+        Ok(findings) => {
+            if !findings.is_empty() {
+                println!("Day 5: {} anomalies in the almanac", findings.len());
+                for finding in &findings {
+                    println!("       {}", finding);
+                }
+            }
+            if findings.iter().any(|finding| finding.anomaly.is_fatal()) {
+                return Err("the almanac has values beyond u64 or a seed without range length".to_string());
+            }
+        }
+        Err(message) => return Err(format!("invalid input\n{}", message))
+    }
+
+    if let Some(explain) = options.get("explain") {
+        let to = options.get("to").unwrap_or("location");
+        for mode in [BuildAlmanacMode::Part1, BuildAlmanacMode::Part2] {
+            parse_category_almanac(&concat_input, mode).and_then(|almanac| {
+                // given seeds are the same for both parts
+                let seeds = if explain.is_empty() { almanac.seeds.clone() } else { parse_value_range(explain, "seed")? };
+                println!("Day 5, {:#?}: seeds {}", mode, format_ranges(&seeds));
+                print_explanation(&almanac, &seeds, to)
+            })?;
+            if !explain.is_empty() {
+                break;
+            }
+        }
+        return Ok(Answers::default());
+    }
+
+    if let Some(locations) = options.get("location_to_seed") {
+        let locations = parse_value_range(locations, "location")?;
+        for mode in [BuildAlmanacMode::Part1, BuildAlmanacMode::Part2] {
+            let almanac = parse_category_almanac(&concat_input, mode)?;
+            let seeds = almanac.location_to_seed(&locations)?;
+            if let BuildAlmanacMode::Part1 = mode {
+                println!("Day 5: locations {} come from the seeds {}", format_ranges(&locations), format_ranges(&seeds));
+            }
+            println!("Day 5, {:#?}: seeds of the almanac among them {}", mode, format_ranges(&seeds.intersection(&almanac.seeds)));
+        }
+        return Ok(Answers::default());
+    }
+
+    let to = options.get("to").unwrap_or("location");
+    let lowest = |mode| parse_category_almanac(&concat_input, mode).and_then(|almanac|
+        if by_inverse { almanac.lowest_seed_conversion_by_inverse(to) } else { almanac.lowest_seed_conversion(to) })
+        .map(|lowest| format!("Lowest {} is {}", to, lowest.to_u64()));
+    Ok(Answers::new(lowest(BuildAlmanacMode::Part1)?, lowest(BuildAlmanacMode::Part2)?))
+}
@@ -0,0 +1,486 @@
+
+type Milliseconds = u64;
+type Millimeter = u64;
+
+#[derive(Debug, PartialEq)]
+struct Race {
+    time:Milliseconds,
+    // current record distance, we must get better
+    minimal_distance:Millimeter
+}
+
+///// Parser
+
+use pest::Parser;
+use pest_derive::Parser;
+
+#[derive(Parser)]
+#[grammar = "../grammar/day6.pest"]
+struct Day6Parser;
+
+
+#[test]
+fn test_parse1() {
+    let parse1 = Day6Parser::parse(Rule::number, "42").unwrap().peek().unwrap();
+    assert_eq!(parse1.as_rule(), Rule::number);
+    assert_eq!(parse1.as_str(), "42");
+
+    assert_eq!(Day6Parser::parse(Rule::number, "6").unwrap().as_str(), "6");
+
+    assert_eq!(Day6Parser::parse(Rule::number_list, "4    15 76").unwrap().as_str(), "4    15 76");
+
+    assert!(Day6Parser::parse(Rule::times, "Time:      7  15   30").is_ok());
+    assert!(Day6Parser::parse(Rule::distances, "Distance:  9  40  200").is_ok());
+
+    assert!(Day6Parser::parse(Rule::file,
+"Time:      7  15   30
+Distance:  9  40  200
+").is_ok());
+
+}
+
+#[test]
+fn test_parse1_2() {
+    let parse1 = Day6Parser::parse(Rule::number_with_spaces, "42 15  3").unwrap().peek().unwrap();
+    assert_eq!(parse1.as_rule(), Rule::number_with_spaces);
+    assert_eq!(parse1.as_str(), "42 15  3");
+
+    assert_eq!(Day6Parser::parse(Rule::number_with_spaces, "42 15  3").unwrap().as_str(), "42 15  3");
+
+    assert!(Day6Parser::parse(Rule::times2, "Time:      7  15   30").is_ok());
+    assert!(Day6Parser::parse(Rule::distances2, "Distance:  9  40  200").is_ok());
+
+    assert!(Day6Parser::parse(Rule::file2,
+"Time:      7  15   30
+Distance:  9  40  200
+").is_ok());
+
+}
+
+use pest::iterators::Pair;
+
+fn build_number_list(number_list_rule:Pair<'_, Rule>) -> Vec<u64> {
+    let mut numbers = Vec::new();
+    for number in number_list_rule.into_inner() {
+        match number.as_rule() {
+            Rule::number => {
+                let number_value = number.as_str().parse::<u64>().unwrap();
+                numbers.push(number_value);
+            }
+            _ => { println!("Unexpected {}", number); }
+        }
+    }
+    numbers
+}
+
+fn build_with_spaces<T:FromStr>(number_rule:Pair<'_, Rule>) -> T where T::Err:Debug {
+    number_rule.as_str().replace(" ", "").parse::<T>().unwrap()
+}
+
+
+fn build_race_list(file_rule:Pair<'_, Rule>) -> Vec<Race> {
+    let mut times:Vec<Milliseconds> = Vec::new();
+    let mut distances:Vec<Millimeter> = Vec::new();
+
+    for entry in file_rule.into_inner() {
+        match entry.as_rule() {
+            Rule::times => {
+                for rule in entry.into_inner() {
+                    match rule.as_rule() {
+                        Rule::number_list => { times = build_number_list(rule); },
+                        _ => unreachable!(),
+                    }
+                }
+            },
+            Rule::distances => {
+                for rule in entry.into_inner() {
+                    match rule.as_rule() {
+                        Rule::number_list => { distances = build_number_list(rule); },
+                        _ => unreachable!(),
+                    }
+                }
+            },
+            Rule::EOI => (),
+            _ => unreachable!(),
+        }
+    }
+    assert_eq!(times.len(), distances.len());
+
+    let mut race_list:Vec<Race> = Vec::new();
+
+    let mut dist_iter = distances.into_iter();
+    for time in times {
+        let distance = dist_iter.next().unwrap();
+        race_list.push(Race { time: time, minimal_distance:distance});
+    }
+
+    race_list
+}
+
+use std::str::FromStr;
+use std::fmt::Debug;
+
+// time and distance of part 2, all digits together can be too large for u64
+fn build_race2_numbers<T:FromStr+Default>(file_rule:Pair<'_, Rule>) -> (T, T) where T::Err:Debug {
+    let mut time = T::default();
+    let mut dist = T::default();
+
+    for entry in file_rule.into_inner() {
+        match entry.as_rule() {
+            Rule::times2 => {
+                for rule in entry.into_inner() {
+                    match rule.as_rule() {
+                        Rule::number_with_spaces => { time = build_with_spaces(rule); },
+                        _ => unreachable!(),
+                    }
+                }
+            },
+            Rule::distances2 => {
+                for rule in entry.into_inner() {
+                    match rule.as_rule() {
+                        Rule::number_with_spaces => { dist = build_with_spaces(rule); },
+                        _ => unreachable!(),
+                    }
+                }
+            },
+            Rule::EOI => (),
+            _ => unreachable!(),
+        }
+    }
+
+    (time, dist)
+}
+
+#[cfg(test)]
+fn build_race2(file_rule:Pair<'_, Rule>) -> Race {
+    let (time, minimal_distance) = build_race2_numbers(file_rule);
+    Race { time, minimal_distance }
+}
+
+#[cfg(test)]
+fn build_example_race_list() -> Vec<Race> {
+    let input = [
+        "Time:      7  15   30",
+        "Distance:  9  40  200"
+    ];
+    let concat_input = input.join("\n");
+    let mut parsed = Day6Parser::parse(Rule::file, &concat_input).unwrap();
+    let file_rule = parsed.next().unwrap();
+    build_race_list(file_rule)
+}
+
+#[cfg(test)]
+fn build_example_race_2() -> Race {
+    let input = [
+        "Time:      7  15   30",
+        "Distance:  9  40  200"
+    ];
+    let concat_input = input.join("\n");
+    let mut parsed = Day6Parser::parse(Rule::file2, &concat_input).unwrap();
+    let file_rule = parsed.next().unwrap();
+    build_race2(file_rule)
+}
+
+#[test]
+fn test_parse2() {
+    assert_eq!(
+        build_number_list(Day6Parser::parse(Rule::number_list, "4    15 76").unwrap().next().unwrap()),
+        vec![4, 15, 76]);
+
+    let race_list = build_example_race_list();
+    assert_eq!(race_list,
+        vec![Race { time: 7,  minimal_distance: 9 },
+             Race { time: 15, minimal_distance: 40 },
+             Race { time: 30, minimal_distance: 200 }]);
+
+}
+
+#[test]
+fn test_parse2_2() {
+    assert_eq!(
+        build_with_spaces::<u64>(Day6Parser::parse(Rule::number_with_spaces, "4    15 76").unwrap().next().unwrap()),
+        41576);
+
+    let race = build_example_race_2();
+    assert_eq!(race,
+        Race { time: 71530,  minimal_distance: 940200 });
+
+}
+
+///// Race logic
+
+impl Race {
+    // at most (u64::MAX / 2)², so it always fits into u128
+    fn calculate_distance(&self, charge:Milliseconds) -> u128 {
+        assert!(charge <= self.time);
+        let travel_time = self.time - charge;
+        let speed = charge; // in Millimeter per Millisecond
+        speed as u128 * travel_time as u128
+    }
+
+    #[cfg(test)]
+    fn ways_to_win_brute_force(&self) -> u64 {
+        let iter_ways_to_win =
+            (0..self.time).filter(
+                |charge| self.calculate_distance(*charge) > self.minimal_distance as u128
+            );
+        iter_ways_to_win.count().try_into().unwrap()
+    }
+
+    // lowest and highest charge which beat the record
+    fn winning_charges(&self) -> Option<(Milliseconds, Milliseconds)> {
+        let (lowest, highest) = winning_charges(&BigUint::from(self.time), &BigUint::from(self.minimal_distance))?;
+        Some((lowest.try_into().unwrap(), highest.try_into().unwrap()))
+    }
+
+    fn ways_to_win(&self) -> BigUint {
+        ways_to_win(&BigUint::from(self.time), &BigUint::from(self.minimal_distance))
+    }
+}
+
+use num::BigUint;
+
+// all charges c with c * (time - c) > minimal_distance, as lowest and highest charge.
+// c * (time - c) = minimal_distance at c = (time ± sqrt(time² - 4 * minimal_distance)) / 2,
+// the square root is rounded down, so that the lowest charge is found at most 2 steps above
+fn winning_charges(time:&BigUint, minimal_distance:&BigUint) -> Option<(BigUint, BigUint)> {
+    let square = time * time;
+    let four_distances = minimal_distance * 4u32;
+    // with equality, the best charge only ties the record
+    if square <= four_distances {
+        return None;
+    }
+    let root = (square - four_distances).sqrt();
+    // the charge with the farthest distance
+    let best = time / 2u32;
+    let mut lowest = (time - &root) / 2u32;
+    while lowest <= best && &lowest * (time - &lowest) <= *minimal_distance {
+        lowest += 1u32;
+    }
+    if lowest > best {
+        return None;
+    }
+    // the distances are symmetric: charge c and time - c give the same distance
+    let highest = time - &lowest;
+    Some((lowest, highest))
+}
+
+fn ways_to_win(time:&BigUint, minimal_distance:&BigUint) -> BigUint {
+    match winning_charges(time, minimal_distance) {
+        Some((lowest, highest)) => highest - lowest + 1u32,
+        None => BigUint::from(0u32)
+    }
+}
+
+// the product does not fit into u64 for a few long races
+fn number_of_ways_to_beat_the_record(races:Vec<Race>) -> BigUint {
+    races.iter().map(|race| race.ways_to_win()).product()
+}
+
+///// Race analysis
+
+struct RaceAnalysis<'a> {
+    race: &'a Race,
+    winning_charges: Option<(Milliseconds, Milliseconds)>,
+    // the charge with the farthest distance (or the lower one of two)
+    best_charge: Milliseconds,
+    max_distance: u128,
+    // negative if the record cannot be beaten
+    margin: i128
+}
+
+impl Race {
+    fn analyze(&self) -> RaceAnalysis<'_> {
+        let best_charge = self.time / 2;
+        let max_distance = self.calculate_distance(best_charge);
+        RaceAnalysis {
+            race: self,
+            winning_charges: self.winning_charges(),
+            best_charge,
+            max_distance,
+            margin: max_distance as i128 - self.minimal_distance as i128
+        }
+    }
+
+    // distance per charge time, bars beating the record are drawn with '#', others with '+'
+    fn plot(&self, width:usize, height:usize) -> String {
+        let columns = min(width as u64, self.time.saturating_add(1)).max(1);
+        let charges:Vec<Milliseconds> = (0..columns)
+            .map(|column| if columns == 1 { 0 } else { (column as u128 * self.time as u128 / (columns - 1) as u128) as Milliseconds })
+            .collect();
+        let max_distance = charges.iter().map(|charge| self.calculate_distance(*charge)).max().unwrap_or(0).max(1);
+        // the lowest distance shown in a row: max_distance * row / height, rounded up.
+        // max_distance * row may not fit into u128, so the quotient and the remainder of max_distance / height are scaled separately
+        let (quotient, remainder) = (max_distance / height as u128, max_distance % height as u128);
+        let level = |row:usize| quotient * row as u128 + (remainder * row as u128).div_ceil(height as u128);
+        let record_row = (1..=height).find(|row| level(*row) > self.minimal_distance as u128);
+        let label_width = max_distance.to_string().len();
+
+        let mut plot = String::new();
+        for row in (1..=height).rev() {
+            let label = if row == height { max_distance.to_string() } else if Some(row) == record_row { self.minimal_distance.to_string() } else { String::new() };
+            plot += &format!("{:>width$} |", label, width = label_width);
+            for charge in &charges {
+                let distance = self.calculate_distance(*charge);
+                plot.push(
+                    if distance >= level(row) { if distance > self.minimal_distance as u128 { '#' } else { '+' } }
+                    else if Some(row) == record_row { '-' }
+                    else { ' ' });
+            }
+            plot.push('\n');
+        }
+        plot += &format!("{:>width$} +{}\n", 0, "-".repeat(charges.len()), width = label_width);
+        plot += &format!("{:>width$}  0{:>time_width$}\n", "", self.time, width = label_width, time_width = charges.len() - 1);
+        plot
+    }
+}
+
+// time 7 ms, record 9 mm: charge 2..=5 wins (4 ways), best charge 3 ms for 12 mm, 3 mm over the record
+impl fmt::Display for RaceAnalysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "time {} ms, record {} mm: ", self.race.time, self.race.minimal_distance)?;
+        match self.winning_charges {
+            Some((lowest, highest)) => write!(f, "charge {}..={} wins ({} ways)", lowest, highest, highest - lowest + 1)?,
+            None => write!(f, "no way to win")?
+        }
+        write!(f, ", best charge {} ms for {} mm, ", self.best_charge, self.max_distance)?;
+        if self.margin >= 0 {
+            write!(f, "{} mm over the record", self.margin)
+        } else {
+            write!(f, "{} mm below the record", -self.margin)
+        }
+    }
+}
+
+use std::cmp::min;
+use std::fmt;
+
+#[test]
+fn test_analyze() {
+    let races = build_example_race_list();
+    let reports:Vec<String> = races.iter().map(|race| race.analyze().to_string()).collect();
+    assert_eq!(reports, [
+        "time 7 ms, record 9 mm: charge 2..=5 wins (4 ways), best charge 3 ms for 12 mm, 3 mm over the record",
+        "time 15 ms, record 40 mm: charge 4..=11 wins (8 ways), best charge 7 ms for 56 mm, 16 mm over the record",
+        "time 30 ms, record 200 mm: charge 11..=19 wins (9 ways), best charge 15 ms for 225 mm, 25 mm over the record",
+    ]);
+    assert_eq!(Race { time: 8, minimal_distance: 16 }.analyze().to_string(),
+        "time 8 ms, record 16 mm: no way to win, best charge 4 ms for 16 mm, 0 mm over the record");
+    assert_eq!(Race { time: 8, minimal_distance: 20 }.analyze().margin, -4);
+
+    // the distances do not fit into u64
+    let race = Race { time: u64::MAX, minimal_distance: u64::MAX };
+    let analysis = race.analyze();
+    let max_distance = (u64::MAX / 2) as u128 * (u64::MAX / 2 + 1) as u128;
+    assert_eq!(analysis.max_distance, max_distance);
+    assert_eq!(analysis.margin, max_distance as i128 - u64::MAX as i128);
+    assert_eq!(analysis.to_string(), format!(
+        "time {} ms, record {} mm: charge 2..={} wins ({} ways), best charge {} ms for {} mm, {} mm over the record",
+        u64::MAX, u64::MAX, u64::MAX - 2, u64::MAX - 3, u64::MAX / 2, max_distance, max_distance - u64::MAX as u128));
+    crate::assert_snapshot("day6/plot_u64_max", &race.plot(60, 12));
+
+    crate::assert_snapshot("day6/plot_part1_race1", &races[0].plot(60, 12));
+    crate::assert_snapshot("day6/plot_part1_race3", &races[2].plot(60, 12));
+    crate::assert_snapshot("day6/plot_part2", &build_example_race_2().plot(60, 12));
+}
+
+#[test]
+fn test_race() {
+    let races = build_example_race_list();
+    let race1 = &races[0];
+    let race2 = &races[1];
+    let race3 = &races[2];
+
+    assert_eq!(race1.calculate_distance(0), 0);
+    assert_eq!(race1.calculate_distance(1), 6);
+    assert_eq!(race1.calculate_distance(2), 10);
+    assert_eq!(race1.calculate_distance(3), 12);
+    assert_eq!(race1.calculate_distance(4), 12);
+    assert_eq!(race1.calculate_distance(5), 10);
+    assert_eq!(race1.calculate_distance(6), 6);
+    assert_eq!(race1.calculate_distance(7), 0);
+
+    assert_eq!(race1.ways_to_win(), BigUint::from(4u32));
+    assert_eq!(race2.ways_to_win(), BigUint::from(8u32));
+    assert_eq!(race3.ways_to_win(), BigUint::from(9u32));
+
+    assert_eq!(number_of_ways_to_beat_the_record(races), BigUint::from(288u32));
+
+    // u64::MAX - 3 ways per race
+    let long_races = (0..3).map(|_| Race { time: u64::MAX, minimal_distance: u64::MAX }).collect();
+    assert_eq!(number_of_ways_to_beat_the_record(long_races), BigUint::from(u64::MAX - 3).pow(3));
+
+}
+
+#[test]
+fn test_race_2() {
+    let race = build_example_race_2();
+    assert_eq!(race.ways_to_win(), BigUint::from(71503u32));
+    assert_eq!(race.ways_to_win_brute_force(), 71503);
+    assert_eq!(race.winning_charges(), Some((14, 71516)));
+}
+
+#[test]
+fn test_closed_form() {
+    for time in 0..80 {
+        for minimal_distance in 0..=(time * time / 4 + 2) {
+            let race = Race { time, minimal_distance };
+            assert_eq!(race.ways_to_win(), BigUint::from(race.ways_to_win_brute_force()), "{:?}", race);
+            if let Some((lowest, highest)) = race.winning_charges() {
+                let record = minimal_distance as u128;
+                assert!(race.calculate_distance(lowest) > record && race.calculate_distance(lowest - 1) <= record);
+                assert!(race.calculate_distance(highest) > record && race.calculate_distance(highest + 1) <= record);
+            }
+        }
+    }
+
+    // ties at the record distance
+    assert_eq!(Race { time: 7, minimal_distance: 12 }.ways_to_win(), BigUint::from(0u32));
+    assert_eq!(Race { time: 8, minimal_distance: 16 }.ways_to_win(), BigUint::from(0u32));
+    assert_eq!(Race { time: 8, minimal_distance: 15 }.winning_charges(), Some((4, 4)));
+
+    // the square of the time does not fit into u64
+    let race = Race { time: u64::MAX, minimal_distance: u64::MAX };
+    assert_eq!(race.winning_charges(), Some((2, u64::MAX - 2)));
+
+    // beyond u64: the best charge 10^20 gives 10^40
+    let time = BigUint::from(2u32) * BigUint::from(10u32).pow(20);
+    let best_distance = BigUint::from(10u32).pow(40);
+    assert_eq!(ways_to_win(&time, &best_distance), BigUint::from(0u32));
+    assert_eq!(ways_to_win(&time, &(&best_distance - 1u32)), BigUint::from(1u32));
+    assert_eq!(ways_to_win(&time, &(&best_distance - 4u32)), BigUint::from(3u32));
+    assert_eq!(ways_to_win(&time, &BigUint::from(0u32)), &time - 1u32);
+}
+
+//use std::time::Instant;
+
+use common::runner::{Answers, Options, Solution};
+
+pub fn part1and2(options:&Options) -> Solution {
+
+    let lines = crate::read_input_lines(6);
+    let concat_input = lines.join("\n");
+    let mut parsed = Day6Parser::parse(Rule::file, &concat_input).unwrap();
+    let file_rule = parsed.next().unwrap();
+    let races = build_race_list(file_rule);
+
+    if options.is_set("report") || options.is_set("plot") {
+        for (index, race) in races.iter().enumerate() {
+            println!("Day 6, race {}: {}", index + 1, race.analyze());
+            if options.is_set("plot") {
+                print!("{}", race.plot(60, 12));
+            }
+        }
+    }
+
+    let part1 = number_of_ways_to_beat_the_record(races);
+
+    let mut parsed2 = Day6Parser::parse(Rule::file2, &concat_input).unwrap();
+    let file_rule2 = parsed2.next().unwrap();
+    let (time, minimal_distance):(BigUint, BigUint) = build_race2_numbers(file_rule2);
+
+    Ok(Answers::new(
+        format!("Number of ways to beat the record is {}", part1),
+        format!("Number of ways to beat the record is {}", ways_to_win(&time, &minimal_distance))))
+}
+
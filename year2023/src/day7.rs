@@ -413,6 +413,19 @@ fn test_game() {
     assert_eq!(get_total_winning(&game), 5905);
 }
 
+#[test]
+fn test_game_ranking() {
+    for (part, name) in [(Part1, "day7/ranking_part1"), (Part2, "day7/ranking_part2")] {
+        let mut game = example_game(part);
+        game.sort_by(|game1, game2| game1.hand.cmp(&game2.hand));
+        let mut ranking = String::new();
+        for (index, hand_with_bid) in game.iter().enumerate() {
+            ranking += &format!("{} {} {:?} {}\n", index + 1, hand_with_bid.hand.to_string(), HandType::of(&hand_with_bid.hand), hand_with_bid.bid);
+        }
+        crate::assert_snapshot(name, &ranking);
+    }
+}
+
 //////////////////////////////////////////
 /// Input parsing
 //////////////////////////////////////////
@@ -471,24 +484,21 @@ QQQJA 483
 /// Productive usage
 //////////////////////////////////////////
 
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-pub fn part1and2() {
+use common::runner::{Answers, Solution};
 
-    let file = File::open("data/day7.input").expect("Could not open data/day7.input");
-    let reader = BufReader::new(file);
+pub fn part1and2() -> Solution {
 
-    let lines:Vec<String> = reader.lines().map( |line| line.unwrap() ).collect();
+    let lines = crate::read_input_lines(7);
     let concat_input = lines.join("\n");
 
-    for part in [Part1, Part2] {
+    let total_winning = |part| {
         let mut parsed = Day7Parser::parse(Rule::file, &concat_input).unwrap();
         let file_rule = parsed.next().unwrap();
         let game = build_game(file_rule, part);
 
-        println!("Day 7, {:?}: Total winnings of set of hands is {}", part, get_total_winning(&game));
-    }
+        format!("Total winnings of set of hands is {}", get_total_winning(&game))
+    };
 
+    Ok(Answers::new(total_winning(Part1), total_winning(Part2)))
 }
\ No newline at end of file
@@ -489,24 +489,29 @@ fn test_network3() {
 
 }
 
+#[test]
+fn test_route_display() {
+    let network = example_network3();
+    let routes = Route::generate_all_routes(&network, Part2);
+    let mut lines:Vec<String> = routes.values().map(|route| route.to_string()).collect();
+    lines.sort();
+    crate::assert_snapshot("day8/routes_network3", &(lines.join("\n") + "\n"));
+}
+
 //////////////////////////////////////////
 /// Productive usage
 //////////////////////////////////////////
 
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-pub fn part1and2() {
+use common::runner::{Answers, Solution};
 
-    let file = File::open("data/day8.input").expect("Could not open data/day8.input");
-    let reader = BufReader::new(file);
+pub fn part1and2() -> Solution {
 
-    let lines:Vec<String> = reader.lines().map( |line| line.unwrap() ).collect();
+    let lines = crate::read_input_lines(8);
     let concat_input = lines.join("\n");
     // last \n is lost. I added one more newline at the end
 
-    for part in [Part1, Part2] {
+    let step_count = |part| {
         let mut parsed = Day8Parser::parse(Rule::file, &concat_input).unwrap();
         let file_rule = parsed.next().unwrap();
         let network = build_network(file_rule, part);
@@ -517,8 +522,8 @@ pub fn part1and2() {
         //    println!("       {}", route);
         //}
 
-        let step_count = network.play(part);
-        println!("Day 8, {:?}: Number of steps is {}", part, step_count);
-    }
+        format!("Number of steps is {}", network.play(part))
+    };
 
+    Ok(Answers::new(step_count(Part1), step_count(Part2)))
 }
\ No newline at end of file
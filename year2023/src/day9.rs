@@ -89,19 +89,16 @@ fn test_history() {
 /// Productive usage
 //////////////////////////////////////////
 
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-pub fn part1and2() {
+use common::runner::{Answers, Solution};
 
-    let file = File::open("data/day9.input").expect("Could not open data/day9.input");
-    let reader = BufReader::new(file);
+pub fn part1and2() -> Solution {
 
-    let lines:Vec<String> = reader.lines().map( |line| line.unwrap() ).collect();
+    let lines = crate::read_input_lines(9);
     let sum_of_next_predictions:Value = lines.iter().map(|line| History::from_str(line).predict_next()).sum();
-    println!("Day 9, Part 1: Sum of next predictions is {}", sum_of_next_predictions);
     let sum_of_prev_predictions:Value = lines.iter().map(|line| History::from_str(line).predict_prev()).sum();
-    println!("Day 9, Part 2: Sum of prev predictions is {}", sum_of_prev_predictions);
 
+    Ok(Answers::new(
+        format!("Sum of next predictions is {}", sum_of_next_predictions),
+        format!("Sum of prev predictions is {}", sum_of_prev_predictions)))
 }
\ No newline at end of file
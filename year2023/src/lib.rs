@@ -0,0 +1,55 @@
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod day10;
+mod day11;
+mod day12;
+
+use common::runner::Day;
+use common::runner::Solver;
+use common::runner::Year;
+
+const DATA_DIR : &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+fn read_input(day:u32) -> String {
+    common::input::read_input(DATA_DIR, day)
+}
+
+fn read_input_lines(day:u32) -> Vec<String> {
+    common::input::read_lines(DATA_DIR, day)
+}
+
+#[cfg(test)]
+const SNAPSHOT_DIR : &str = concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots");
+
+// compare a rendering with year2023/snapshots/<name>.snap
+#[cfg(test)]
+fn assert_snapshot(name:&str, actual:&str) {
+    common::snapshot::assert_snapshot(SNAPSHOT_DIR, name, actual)
+}
+
+pub fn year() -> Year {
+    Year {
+        year: 2023,
+        data_dir: DATA_DIR,
+        days: vec![
+            Day { day:  1, solve: Solver::WithOptions(day1::part1and2) },
+            Day { day:  2, solve: Solver::WithOptions(day2::part1and2) },
+            Day { day:  3, solve: Solver::WithOptions(day3::part1and2) },
+            Day { day:  4, solve: Solver::WithOptions(day4::part1and2) },
+            Day { day:  5, solve: Solver::WithOptions(day5::part1and2) },
+            Day { day:  6, solve: Solver::WithOptions(day6::part1and2) },
+            Day { day:  7, solve: Solver::Plain(day7::part1and2) },
+            Day { day:  8, solve: Solver::Plain(day8::part1and2) },
+            Day { day:  9, solve: Solver::Plain(day9::part1and2) },
+            Day { day: 10, solve: Solver::Plain(day10::part1and2) },
+            Day { day: 11, solve: Solver::Plain(day11::part1and2) }
+        ]
+    }
+}